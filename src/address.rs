@@ -0,0 +1,257 @@
+//! The `address` mod includes `Address`, which parses and validates the transparent and shielded address encodings `zcashd` uses.
+
+use crate::error::{InvalidRequest, ParseAddressError};
+use crate::{Network, ResponseResult};
+use bech32::{FromBase32, ToBase32, Variant};
+use std::fmt;
+use std::str::FromStr;
+
+const P2PKH_PREFIXES: &[(Network, [u8; 2])] =
+    &[(Network::Main, [0x1c, 0xb8]), (Network::Test, [0x1d, 0x25])];
+const P2SH_PREFIXES: &[(Network, [u8; 2])] =
+    &[(Network::Main, [0x1c, 0xbd]), (Network::Test, [0x1c, 0xba])];
+const SPROUT_PREFIXES: &[(Network, [u8; 2])] =
+    &[(Network::Main, [0x16, 0x9a]), (Network::Test, [0x16, 0xb6])];
+const SAPLING_HRPS: &[(Network, &str)] = &[
+    (Network::Main, "zs"),
+    (Network::Test, "ztestsapling"),
+    (Network::Regtest, "zregtestsapling"),
+];
+const UNIFIED_HRPS: &[(Network, &str)] = &[
+    (Network::Main, "u"),
+    (Network::Test, "utest"),
+    (Network::Regtest, "uregtest"),
+];
+
+/// An `Address` is a transparent or shielded Zcash address, parsed from and displayed as its Base58Check, Bech32 or Bech32m encoding.
+///
+/// Testnet and regtest share the transparent and Sprout encodings, so those addresses have `Network::Test` whichever of the two they are for; see `is_valid_for`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Address {
+    /// A transparent pay-to-public-key-hash address, e.g. `t1...` on mainnet.
+    P2pkh { network: Network, hash: [u8; 20] },
+    /// A transparent pay-to-script-hash address, e.g. `t3...` on mainnet.
+    P2sh { network: Network, hash: [u8; 20] },
+    /// A Sprout shielded address, e.g. `zc...` on mainnet.
+    Sprout { network: Network, bytes: [u8; 64] },
+    /// A Sapling shielded address, e.g. `zs1...` on mainnet.
+    Sapling { network: Network, bytes: [u8; 43] },
+    /// A unified address, e.g. `u1...` on mainnet. Its receivers are kept in their encoded (jumbled) form.
+    Unified { network: Network, bytes: Vec<u8> },
+}
+
+impl Address {
+    /// The `Network` the address is encoded for.
+    pub fn network(&self) -> Network {
+        match self {
+            Address::P2pkh { network, .. }
+            | Address::P2sh { network, .. }
+            | Address::Sprout { network, .. }
+            | Address::Sapling { network, .. }
+            | Address::Unified { network, .. } => *network,
+        }
+    }
+
+    /// Whether the address is a transparent P2PKH or P2SH address, which can't be sent a memo.
+    pub fn is_transparent(&self) -> bool {
+        matches!(self, Address::P2pkh { .. } | Address::P2sh { .. })
+    }
+
+    /// Whether a node following `network` accepts the address.
+    pub fn is_valid_for(&self, network: Network) -> bool {
+        match self {
+            Address::P2pkh { .. }
+            | Address::P2sh { .. }
+            | Address::Sprout { .. }
+                if network == Network::Regtest =>
+            {
+                self.network() == Network::Test
+            }
+            _ => self.network() == network,
+        }
+    }
+
+    fn from_base58check(s: &str) -> Result<Self, ParseAddressError> {
+        let decoded = bs58::decode(s).with_check(None).into_vec()?;
+        if decoded.len() < 2 {
+            return Err(ParseAddressError::UnknownPrefix);
+        }
+        let (prefix, payload) = decoded.split_at(2);
+        if let Some(network) = lookup(P2PKH_PREFIXES, prefix) {
+            let hash = copy_payload(payload)?;
+            Ok(Address::P2pkh { network, hash })
+        } else if let Some(network) = lookup(P2SH_PREFIXES, prefix) {
+            let hash = copy_payload(payload)?;
+            Ok(Address::P2sh { network, hash })
+        } else if let Some(network) = lookup(SPROUT_PREFIXES, prefix) {
+            let bytes = copy_payload(payload)?;
+            Ok(Address::Sprout { network, bytes })
+        } else {
+            Err(ParseAddressError::UnknownPrefix)
+        }
+    }
+
+    fn from_bech32(s: &str) -> Result<Self, ParseAddressError> {
+        let (hrp, data, variant) = bech32::decode(s)?;
+        let bytes = Vec::<u8>::from_base32(&data)?;
+        let hrp = hrp.as_str();
+        if let Some(network) = lookup(SAPLING_HRPS, hrp) {
+            expect_variant(variant, Variant::Bech32)?;
+            let bytes = copy_payload(&bytes)?;
+            Ok(Address::Sapling { network, bytes })
+        } else if let Some(network) = lookup(UNIFIED_HRPS, hrp) {
+            expect_variant(variant, Variant::Bech32m)?;
+            Ok(Address::Unified { network, bytes })
+        } else {
+            Err(ParseAddressError::UnknownPrefix)
+        }
+    }
+}
+
+fn lookup<P, Q>(table: &[(Network, P)], prefix: &Q) -> Option<Network>
+where
+    P: AsRef<Q>,
+    Q: PartialEq + ?Sized,
+{
+    table
+        .iter()
+        .find(|(_, p)| p.as_ref() == prefix)
+        .map(|(network, _)| *network)
+}
+
+fn prefix_for<P: Copy>(table: &[(Network, P)], network: Network) -> P {
+    table
+        .iter()
+        .find(|(n, _)| *n == network)
+        .or_else(|| table.iter().find(|(n, _)| *n == Network::Test))
+        .map(|(_, p)| *p)
+        .unwrap()
+}
+
+fn copy_payload<const N: usize>(
+    payload: &[u8],
+) -> Result<[u8; N], ParseAddressError> {
+    use std::convert::TryInto as _;
+
+    payload
+        .try_into()
+        .map_err(|_| ParseAddressError::WrongLength {
+            expected: N,
+            found: payload.len(),
+        })
+}
+
+fn expect_variant(
+    found: Variant,
+    expected: Variant,
+) -> Result<(), ParseAddressError> {
+    if found == expected {
+        Ok(())
+    } else {
+        Err(ParseAddressError::WrongVariant)
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let base58check = |prefix: [u8; 2], payload: &[u8]| {
+            let mut data = prefix.to_vec();
+            data.extend_from_slice(payload);
+            bs58::encode(data).with_check().into_string()
+        };
+        let bech32 = |hrp: &str, payload: &[u8], variant| {
+            bech32::encode(hrp, payload.to_base32(), variant)
+                .map_err(|_| fmt::Error)
+        };
+        let encoded = match self {
+            Address::P2pkh { network, hash } => {
+                base58check(prefix_for(P2PKH_PREFIXES, *network), hash)
+            }
+            Address::P2sh { network, hash } => {
+                base58check(prefix_for(P2SH_PREFIXES, *network), hash)
+            }
+            Address::Sprout { network, bytes } => {
+                base58check(prefix_for(SPROUT_PREFIXES, *network), bytes)
+            }
+            Address::Sapling { network, bytes } => bech32(
+                prefix_for(SAPLING_HRPS, *network),
+                bytes,
+                Variant::Bech32,
+            )?,
+            Address::Unified { network, bytes } => bech32(
+                prefix_for(UNIFIED_HRPS, *network),
+                bytes,
+                Variant::Bech32m,
+            )?,
+        };
+        f.write_str(&encoded)
+    }
+}
+
+impl FromStr for Address {
+    type Err = ParseAddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lowercase = s.to_ascii_lowercase();
+        let is_bech32 = SAPLING_HRPS
+            .iter()
+            .chain(UNIFIED_HRPS)
+            .any(|(_, hrp)| lowercase.starts_with(&format!("{}1", hrp)));
+        if is_bech32 {
+            Address::from_bech32(s)
+        } else {
+            Address::from_base58check(s)
+        }
+    }
+}
+
+impl_serde_via_str!(Address);
+
+/// Implemented by the parameter types which hold addresses, so that they can be checked against the `Network` of the node before a request is sent.
+pub(crate) trait AddressParam {
+    fn addresses(&self) -> Vec<&Address>;
+}
+
+impl AddressParam for Address {
+    fn addresses(&self) -> Vec<&Address> {
+        vec![self]
+    }
+}
+
+impl<A: AddressParam> AddressParam for Option<A> {
+    fn addresses(&self) -> Vec<&Address> {
+        self.iter().flat_map(AddressParam::addresses).collect()
+    }
+}
+
+impl<A: AddressParam> AddressParam for Vec<A> {
+    fn addresses(&self) -> Vec<&Address> {
+        self.iter().flat_map(AddressParam::addresses).collect()
+    }
+}
+
+/// Reject any address param which the node, if its `Network` is known, wouldn't accept.
+pub(crate) fn check_network(
+    network: Option<Network>,
+    method: &'static str,
+    params: Vec<(&'static str, Vec<&Address>)>,
+) -> ResponseResult<()> {
+    let network = match network {
+        Some(network) => network,
+        None => return Ok(()),
+    };
+    for (param, addresses) in params {
+        if let Some(address) =
+            addresses.into_iter().find(|a| !a.is_valid_for(network))
+        {
+            return Err(InvalidRequest::WrongNetwork {
+                method,
+                param,
+                node: network,
+                address: address.network(),
+            }
+            .into());
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,254 @@
+//! The `amount` mod includes `ZecAmount` and `Zatoshis`, which represent ZEC- and zatoshi-denominated values exactly, as integer numbers of zatoshis.
+
+use crate::error::ParseAmountError;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+/// The number of zatoshis in one ZEC.
+pub const COIN: i64 = 100_000_000;
+
+/// The total supply of ZEC, in zatoshis, which bounds every valid `ZecAmount` in either direction.
+pub const MAX_MONEY: i64 = 21_000_000 * COIN;
+
+/// A `Zatoshis` value is a zatoshi-denominated field, such as `valueZat` or `satoshis`. One ZEC is 100,000,000 zatoshis.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Deserialize,
+    serde::Serialize,
+)]
+#[serde(transparent)]
+pub struct Zatoshis(pub i64);
+
+impl Zatoshis {
+    /// Add, returning `None` on overflow.
+    pub fn checked_add(self, rhs: Zatoshis) -> Option<Zatoshis> {
+        self.0.checked_add(rhs.0).map(Zatoshis)
+    }
+
+    /// Subtract, returning `None` on overflow.
+    pub fn checked_sub(self, rhs: Zatoshis) -> Option<Zatoshis> {
+        self.0.checked_sub(rhs.0).map(Zatoshis)
+    }
+
+    /// Multiply by a count, returning `None` on overflow.
+    pub fn checked_mul(self, rhs: i64) -> Option<Zatoshis> {
+        self.0.checked_mul(rhs).map(Zatoshis)
+    }
+
+    /// Negate, returning `None` on overflow.
+    pub fn checked_neg(self) -> Option<Zatoshis> {
+        self.0.checked_neg().map(Zatoshis)
+    }
+}
+
+impl fmt::Display for Zatoshis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A `ZecAmount` is a ZEC-denominated value, such as a balance, fee or `amount` param. It is held as a whole number of zatoshis within `MAX_MONEY` of zero, so it can't have sub-zatoshi precision, and arithmetic which would leave that range fails.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ZecAmount(Zatoshis);
+
+impl ZecAmount {
+    pub const ZERO: ZecAmount = ZecAmount(Zatoshis(0));
+    pub const MAX: ZecAmount = ZecAmount(Zatoshis(MAX_MONEY));
+
+    /// The amount of `zatoshis`, if it is within `MAX_MONEY` of zero.
+    pub fn from_zatoshis(zatoshis: i64) -> Result<ZecAmount, ParseAmountError> {
+        if (-MAX_MONEY..=MAX_MONEY).contains(&zatoshis) {
+            Ok(ZecAmount(Zatoshis(zatoshis)))
+        } else {
+            Err(ParseAmountError::OutOfRange)
+        }
+    }
+
+    /// The amount in zatoshis.
+    pub fn to_zatoshis(self) -> Zatoshis {
+        self.0
+    }
+
+    pub fn is_negative(self) -> bool {
+        (self.0).0 < 0
+    }
+
+    pub fn abs(self) -> ZecAmount {
+        ZecAmount(Zatoshis((self.0).0.abs()))
+    }
+
+    /// Add, returning `None` if the sum is beyond `MAX_MONEY`.
+    pub fn checked_add(self, rhs: ZecAmount) -> Option<ZecAmount> {
+        self.0
+            .checked_add(rhs.0)
+            .and_then(|z| ZecAmount::try_from(z).ok())
+    }
+
+    /// Subtract, returning `None` if the difference is beyond `MAX_MONEY`.
+    pub fn checked_sub(self, rhs: ZecAmount) -> Option<ZecAmount> {
+        self.0
+            .checked_sub(rhs.0)
+            .and_then(|z| ZecAmount::try_from(z).ok())
+    }
+
+    /// Multiply by a count, returning `None` if the product is beyond `MAX_MONEY`.
+    pub fn checked_mul(self, rhs: i64) -> Option<ZecAmount> {
+        self.0
+            .checked_mul(rhs)
+            .and_then(|z| ZecAmount::try_from(z).ok())
+    }
+
+    /// Negate, which can't leave the range, but is `checked_` for symmetry with the other operations.
+    pub fn checked_neg(self) -> Option<ZecAmount> {
+        self.0
+            .checked_neg()
+            .and_then(|z| ZecAmount::try_from(z).ok())
+    }
+}
+
+impl TryFrom<Zatoshis> for ZecAmount {
+    type Error = ParseAmountError;
+
+    fn try_from(zatoshis: Zatoshis) -> Result<Self, Self::Error> {
+        ZecAmount::from_zatoshis(zatoshis.0)
+    }
+}
+
+impl From<ZecAmount> for Zatoshis {
+    fn from(amount: ZecAmount) -> Zatoshis {
+        amount.0
+    }
+}
+
+impl TryFrom<rust_decimal::Decimal> for ZecAmount {
+    type Error = ParseAmountError;
+
+    fn try_from(zec: rust_decimal::Decimal) -> Result<Self, Self::Error> {
+        zec.to_string().parse()
+    }
+}
+
+impl From<ZecAmount> for rust_decimal::Decimal {
+    fn from(amount: ZecAmount) -> rust_decimal::Decimal {
+        rust_decimal::Decimal::new((amount.0).0, 8)
+    }
+}
+
+/// Displays the amount in ZEC with all 8 decimal places, as `zcashd` does.
+impl fmt::Display for ZecAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let zatoshis = (self.0).0;
+        let sign = if zatoshis < 0 { "-" } else { "" };
+        let zatoshis = zatoshis.abs();
+        write!(f, "{}{}.{:08}", sign, zatoshis / COIN, zatoshis % COIN)
+    }
+}
+
+/// Parses a decimal number of ZEC, like `-1.5` or `0.00000001`, exactly.
+impl FromStr for ZecAmount {
+    type Err = ParseAmountError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s),
+        };
+        let (whole, fraction) = match digits.find('.') {
+            Some(point) => (&digits[..point], &digits[point + 1..]),
+            None => (digits, ""),
+        };
+        let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+        if (whole.is_empty() && fraction.is_empty())
+            || !is_digits(whole)
+            || !is_digits(fraction)
+        {
+            return Err(ParseAmountError::Malformed);
+        }
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.len() > 8 {
+            return Err(ParseAmountError::TooPrecise);
+        }
+        let whole: i64 = match whole.trim_start_matches('0') {
+            "" => 0,
+            whole => whole.parse().map_err(|_| ParseAmountError::OutOfRange)?,
+        };
+        let fraction: i64 = format!("{:0<8}", fraction).parse().unwrap();
+        let zatoshis = whole
+            .checked_mul(COIN)
+            .and_then(|z| z.checked_add(fraction))
+            .ok_or(ParseAmountError::OutOfRange)?;
+        ZecAmount::from_zatoshis(if negative { -zatoshis } else { zatoshis })
+    }
+}
+
+/// Serializes as a JSON number of ZEC, which is what `zcashd` takes for amount params. Every valid amount is below 2^25 ZEC, where an `f64` resolves better than a zatoshi, so the shortest decimal which identifies the `f64` is exactly the amount.
+impl serde::Serialize for ZecAmount {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_f64((self.0).0 as f64 / COIN as f64)
+    }
+}
+
+/// Deserializes a JSON number of ZEC, by parsing the shortest decimal representation of the `f64` (see `Serialize`) rather than scaling it, or a string of ZEC, which is how `z_gettotalbalance` sends its amounts.
+impl<'de> serde::Deserialize<'de> for ZecAmount {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct ZecVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ZecVisitor {
+            type Value = ZecAmount;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an amount of ZEC, as a number or a string")
+            }
+
+            fn visit_i64<E: serde::de::Error>(
+                self,
+                zec: i64,
+            ) -> Result<ZecAmount, E> {
+                zec.checked_mul(COIN)
+                    .ok_or(ParseAmountError::OutOfRange)
+                    .and_then(ZecAmount::from_zatoshis)
+                    .map_err(E::custom)
+            }
+
+            fn visit_u64<E: serde::de::Error>(
+                self,
+                zec: u64,
+            ) -> Result<ZecAmount, E> {
+                i64::try_from(zec)
+                    .map_err(|_| E::custom(ParseAmountError::OutOfRange))
+                    .and_then(|zec| self.visit_i64(zec))
+            }
+
+            fn visit_f64<E: serde::de::Error>(
+                self,
+                zec: f64,
+            ) -> Result<ZecAmount, E> {
+                self.visit_str(&zec.to_string())
+            }
+
+            fn visit_str<E: serde::de::Error>(
+                self,
+                zec: &str,
+            ) -> Result<ZecAmount, E> {
+                zec.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(ZecVisitor)
+    }
+}
@@ -0,0 +1,87 @@
+//! The `auth` mod includes `Auth`, which selects how a `Client` authenticates to `zcashd`.
+
+use base64::Engine as _;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+/// An `Auth` is how a `Client` authenticates to `zcashd`'s RPC server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Auth {
+    /// Send no `Authorization` header, e.g. for a proxy which adds its own.
+    None,
+    /// Authenticate as `rpcuser` with `rpcpassword`, or as a user configured with `rpcauth`.
+    UserPass { user: String, password: String },
+    /// Authenticate with the contents of the `.cookie` file `zcashd` writes to its data directory when no `rpcpassword` is configured. `zcashd` writes a new cookie each time it starts, so the file is read again whenever it has been modified since the last request, and when the node refuses the cookie last read.
+    CookieFile(PathBuf),
+}
+
+impl Auth {
+    /// The `UserPass` for `cookie`, the `user:password` contents of a cookie file.
+    pub fn from_cookie(cookie: &str) -> Auth {
+        let (user, password) = split_cookie(cookie);
+        Auth::UserPass {
+            user: user.to_string(),
+            password: password.to_string(),
+        }
+    }
+}
+
+/// An `Auth` along with its `Authorization` header, which for a cookie file is cached until the file is modified.
+pub(crate) struct Credentials {
+    auth: Auth,
+    cached: Option<(Option<SystemTime>, String)>,
+}
+
+impl Credentials {
+    pub(crate) fn new(auth: Auth) -> Self {
+        Credentials { auth, cached: None }
+    }
+
+    /// The header, read afresh from a cookie file whatever its modification time, e.g. after the node refused the cached one, since a cookie rewritten within the file system's timestamp resolution looks unmodified.
+    pub(crate) fn reread(&mut self) -> std::io::Result<Option<String>> {
+        self.cached = None;
+        self.header()
+    }
+
+    pub(crate) fn header(&mut self) -> std::io::Result<Option<String>> {
+        match &self.auth {
+            Auth::None => Ok(None),
+            Auth::UserPass { user, password } => {
+                Ok(Some(basic_auth(user, password)))
+            }
+            Auth::CookieFile(path) => {
+                let modified = std::fs::metadata(path)?.modified().ok();
+                match &self.cached {
+                    Some((cached, header))
+                        if modified.is_some() && *cached == modified =>
+                    {
+                        Ok(Some(header.clone()))
+                    }
+                    _ => {
+                        let cookie = std::fs::read_to_string(path)?;
+                        let (user, password) = split_cookie(&cookie);
+                        let header = basic_auth(user, password);
+                        self.cached = Some((modified, header.clone()));
+                        Ok(Some(header))
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn split_cookie(cookie: &str) -> (&str, &str) {
+    let cookie = cookie.trim_end();
+    match cookie.find(':') {
+        Some(colon) => (&cookie[..colon], &cookie[colon + 1..]),
+        None => (cookie, ""),
+    }
+}
+
+pub(crate) fn basic_auth(user: &str, password: &str) -> String {
+    let credentials = format!("{}:{}", user, password);
+    format!(
+        "Basic {}",
+        base64::engine::general_purpose::STANDARD.encode(credentials)
+    )
+}
@@ -0,0 +1,113 @@
+//! The `bytes` mod includes types for the hex-encoded hashes, ids and byte strings which `zcashd` sends and receives.
+
+use crate::error::ParseHexError;
+use std::fmt;
+use std::str::FromStr;
+
+/// A `HexBytes` is an arbitrary byte string, such as a serialized transaction or script, which `zcashd` encodes as hex in the same byte order.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct HexBytes(pub Vec<u8>);
+
+impl HexBytes {
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<Vec<u8>> for HexBytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        HexBytes(bytes)
+    }
+}
+
+impl AsRef<[u8]> for HexBytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl fmt::Display for HexBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&hex::encode(&self.0))
+    }
+}
+
+impl FromStr for HexBytes {
+    type Err = ParseHexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(HexBytes(hex::decode(s)?))
+    }
+}
+
+/// Defines a 32-byte hash which, like `uint256::GetHex` in `zcashd`, is displayed with its bytes reversed.
+macro_rules! reversed_hash {
+    ($(#[$doc:meta])* $name:ident) => {
+        $(#[$doc])*
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $name([u8; 32]);
+
+        impl $name {
+            /// Wraps bytes in internal byte order, the order in which they are serialized in blocks and transactions, which is the reverse of the hex `zcashd` displays.
+            pub fn from_bytes(bytes: [u8; 32]) -> Self {
+                $name(bytes)
+            }
+
+            /// The bytes in internal byte order.
+            pub fn as_bytes(&self) -> &[u8; 32] {
+                &self.0
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}({})", stringify!($name), self)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut displayed = self.0;
+                displayed.reverse();
+                f.write_str(&hex::encode(displayed))
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseHexError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut bytes = [0u8; 32];
+                let decoded = hex::decode(s)?;
+                if decoded.len() != bytes.len() {
+                    return Err(ParseHexError::WrongLength {
+                        expected: bytes.len(),
+                        found: decoded.len(),
+                    });
+                }
+                bytes.copy_from_slice(&decoded);
+                bytes.reverse();
+                Ok($name(bytes))
+            }
+        }
+
+        impl_serde_via_str!($name);
+    };
+}
+
+impl_serde_via_str!(HexBytes);
+
+reversed_hash!(
+    /// A `BlockHash` identifies a block, e.g. the `hash` and `previousblockhash` of `getblock`.
+    BlockHash
+);
+
+reversed_hash!(
+    /// A `TxId` identifies a transaction, e.g. the `txid` of `getrawtransaction` and the entries of `getrawmempool`.
+    TxId
+);
+
+reversed_hash!(
+    /// A `MerkleRoot` is the root of a block's transaction tree or of a note commitment tree, e.g. the `merkleroot` and `finalsaplingroot` of `getblock`.
+    MerkleRoot
+);
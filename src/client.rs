@@ -1,13 +1,20 @@
 //! Includes both `Client` and all of the RPC response types.
 #[macro_use]
 mod callrpc;
+pub mod batch;
+pub mod builder;
+pub mod retry;
+pub mod subcomponents;
+pub mod transport;
 pub mod utils;
 
-use zcashrpc_api::{
-    generate::GenerateResponse, getblockchaininfo::GetblockchaininfoResponse,
-    z_getnewaddress::ZGetnewaddressResponse,
+use crate::operation::{Operation, OperationId, OperationStatus};
+use crate::stream::BlockStream;
+use crate::wallet::{Recipient, SendManyRequest};
+use crate::{
+    Address, Auth, BlockHash, HexBytes, Memo, Network, Rescan, ResponseResult,
+    TxId, ZecAmount,
 };
-use crate::ResponseResult;
 use serde::de::DeserializeOwned;
 use std::future::Future;
 
@@ -21,37 +28,255 @@ impl Client {
     /// - `hostport` is a host/ip with an optional `:PORT` appended.
     /// - `authcookie` is the contents of `~/.zcash/.cookie`.
     pub fn new(hostport: String, authcookie: String) -> Client {
+        Client::with_auth(hostport, Auth::from_cookie(&authcookie))
+    }
+
+    /// Construct a new `Client` for `hostport` which authenticates with `auth`, e.g. an `Auth::CookieFile` which keeps working after `zcashd` restarts and writes a new cookie.
+    pub fn with_auth(hostport: String, auth: Auth) -> Client {
         Client {
-            inner: utils::InnerCli::new(hostport, authcookie),
+            inner: utils::InnerCli::new(hostport, auth),
+        }
+    }
+
+    /// Construct a `Client` which sends its requests with `transport`, e.g. a `ReplayTransport`.
+    pub fn from_transport(
+        transport: impl transport::Transport + 'static,
+    ) -> Client {
+        Client {
+            inner: utils::InnerCli::with_transport(std::sync::Arc::new(
+                transport,
+            )),
+        }
+    }
+
+    /// Begin configuring a `Client` which sends requests to `url`, e.g. `https://proxy.example/zcashd/`. See `ClientBuilder`.
+    pub fn builder(url: impl Into<String>) -> builder::ClientBuilder {
+        builder::ClientBuilder::new(url)
+    }
+
+    /// Declare which `Network` the node follows, so that address params encoded for another network are rejected before the request is sent.
+    pub fn with_network(mut self, network: Network) -> Client {
+        self.inner.network = Some(network);
+        self
+    }
+
+    /// The `Network` given to `with_network`, if any.
+    pub fn network(&self) -> Option<Network> {
+        self.inner.network
+    }
+
+    /// Retry requests which fail with a transient error, as `policy` allows. Without a `RetryPolicy`, every error is returned as soon as it occurs.
+    pub fn with_retry(mut self, policy: retry::RetryPolicy) -> Client {
+        self.inner.retry = Some(std::sync::Arc::new(policy));
+        self
+    }
+
+    /// Check the `chain` the node reports in `getblockchaininfo` against the `Network` given to `with_network`. If they differ, this and every later request fails with `Error::NetworkMismatch`. A `Client` without a `Network` adopts the node's.
+    pub async fn verify_network(&mut self) -> ResponseResult<Network> {
+        let node = self.getblockchaininfo().await?.chain;
+        match self.inner.network {
+            Some(configured) if configured != node => {
+                self.inner.mismatch = Some((configured, node));
+                Err(crate::Error::NetworkMismatch { configured, node })
+            }
+            _ => {
+                self.inner.network = Some(node);
+                Ok(node)
+            }
+        }
+    }
+
+    /// Begin a `Batch` of requests which all produce an `R` response. The requests are only sent once `Batch::send` is awaited.
+    pub fn batch<R>(&mut self) -> batch::Batch<'_, R>
+    where
+        R: DeserializeOwned,
+    {
+        batch::Batch::new(self)
+    }
+
+    zcashrpc_macros::declare_rpc_client_methods!("rpc_methods.json");
+
+    /// Call `getblock` with verbosity 0, returning the serialized block as hex.
+    pub fn getblock_hex(
+        &mut self,
+        hash_or_height: String,
+    ) -> impl Future<Output = ResponseResult<HexBytes>> {
+        rpc_call!(self.getblock(hash_or_height, 0))
+    }
+
+    /// Call `getblock` with verbosity 1, returning the block header fields and the txids of its transactions.
+    pub fn getblock_verbose(
+        &mut self,
+        hash_or_height: String,
+    ) -> impl Future<Output = ResponseResult<subcomponents::getblock::Verbose>>
+    {
+        rpc_call!(self.getblock(hash_or_height, 1))
+    }
+
+    /// Call `getblock` with verbosity 2, returning the block header fields and all of its transactions, decoded.
+    pub fn getblock_txs(
+        &mut self,
+        hash_or_height: String,
+    ) -> impl Future<Output = ResponseResult<subcomponents::getblock::VeryVerbose>>
+    {
+        rpc_call!(self.getblock(hash_or_height, 2))
+    }
+
+    /// Call `getrawtransaction` with verbose 0, returning the serialized transaction as hex.
+    pub fn getrawtransaction_hex(
+        &mut self,
+        txid: TxId,
+    ) -> impl Future<Output = ResponseResult<HexBytes>> {
+        rpc_call!(self.getrawtransaction(txid, 0))
+    }
+
+    /// Call `getrawtransaction` with verbose 1, returning the decoded transaction.
+    pub fn getrawtransaction_verbose(
+        &mut self,
+        txid: TxId,
+    ) -> impl Future<
+        Output = ResponseResult<subcomponents::getrawtransaction::Verbose>,
+    > {
+        rpc_call!(self.getrawtransaction(txid, 1))
+    }
+
+    /// Call `z_getoperationstatus`, returning the status of the operations in `operationids`, or of every operation the node knows of. The operations are kept, so their status can be asked for again.
+    pub fn z_getoperationstatus(
+        &mut self,
+        operationids: Option<Vec<OperationId>>,
+    ) -> impl Future<Output = ResponseResult<Vec<OperationStatus>>> {
+        rpc_call!(self.z_getoperationstatus(; operationids))
+    }
+
+    /// Call `z_getoperationresult`, returning the status of the finished operations among `operationids`, or of every finished operation the node knows of. The node forgets the operations it returns.
+    pub fn z_getoperationresult(
+        &mut self,
+        operationids: Option<Vec<OperationId>>,
+    ) -> impl Future<Output = ResponseResult<Vec<OperationStatus>>> {
+        rpc_call!(self.z_getoperationresult(; operationids))
+    }
+
+    /// An `Operation` handle on the operation `opid`, e.g. one started by an earlier process.
+    pub fn operation(&self, opid: OperationId) -> Operation {
+        Operation::new(self.share(), opid)
+    }
+
+    /// A `BlockStream` of the blocks of the node's best chain from `start_height`, which follows the tip as new blocks arrive.
+    pub fn block_stream(&self, start_height: u32) -> BlockStream {
+        BlockStream::new(self.share(), start_height)
+    }
+
+    /// Call `z_sendmany` with the params of `request`, returning an `Operation` which can be waited on for the `txid` of the transaction it sends. The request is checked with `SendManyRequest::validate` first, and isn't sent if it fails.
+    pub fn z_sendmany_operation(
+        &mut self,
+        request: SendManyRequest,
+    ) -> impl Future<Output = ResponseResult<Operation>> {
+        use crate::error::InvalidRequest;
+
+        let client = self.share();
+        let opid = request.validate().map(|()| {
+            let (fromaddress, amounts, minconf, fee) = request.into_params();
+            self.z_sendmany(fromaddress, amounts, minconf, fee)
+        });
+        async move {
+            let opid = opid
+                .map_err(|e| {
+                    crate::Error::from(InvalidRequest::SendMany(e))
+                        .in_call("z_sendmany", None)
+                })?
+                .await?;
+            Ok(Operation::new(client, OperationId(opid)))
         }
     }
 
-    zcashrpc_macros::declare_rpc_client_methods! {
-        Getblockchaininfo,
-        ZGetnewaddress,
-        Generate (how_many: u32),
+    /// Call `z_shieldcoinbase`, returning an `Operation` for the shielding transaction along with the rest of the response.
+    pub fn z_shieldcoinbase_operation(
+        &mut self,
+        fromaddress: String,
+        toaddress: Address,
+        fee: Option<ZecAmount>,
+        limit: Option<u32>,
+    ) -> impl Future<
+        Output = ResponseResult<(
+            Operation,
+            subcomponents::z_shieldcoinbase::ZShieldcoinbaseResponse,
+        )>,
+    > {
+        let client = self.share();
+        let response =
+            self.z_shieldcoinbase(fromaddress, toaddress, fee, limit);
+        async move {
+            let response = response.await?;
+            let opid = OperationId(response.opid.clone());
+            Ok((Operation::new(client, opid), response))
+        }
+    }
+
+    /// Call `z_mergetoaddress`, returning an `Operation` for the merging transaction along with the rest of the response.
+    pub fn z_mergetoaddress_operation(
+        &mut self,
+        fromaddresses: Vec<String>,
+        toaddress: Address,
+        fee: Option<ZecAmount>,
+        transparent_limit: Option<u32>,
+        shielded_limit: Option<u32>,
+        memo: Option<Memo>,
+    ) -> impl Future<
+        Output = ResponseResult<(
+            Operation,
+            subcomponents::z_mergetoaddress::ZMergetoaddressResponse,
+        )>,
+    > {
+        let client = self.share();
+        let response = self.z_mergetoaddress(
+            fromaddresses,
+            toaddress,
+            fee,
+            transparent_limit,
+            shielded_limit,
+            memo,
+        );
+        async move {
+            let response = response.await?;
+            let opid = OperationId(response.opid.clone());
+            Ok((Operation::new(client, opid), response))
+        }
     }
 }
 
 impl Client {
+    /// A `Client` for the same node, with the same settings, which an `Operation` can own.
+    fn share(&self) -> Client {
+        Client {
+            inner: self.inner.share(),
+        }
+    }
+
     fn make_request<R>(
         &mut self,
         method: &'static str,
-        args: Vec<serde_json::Value>,
+        args: ResponseResult<Vec<serde_json::Value>>,
     ) -> impl Future<Output = ResponseResult<R>>
     where
         R: DeserializeOwned,
     {
         use crate::{envelope::ResponseEnvelope, json};
 
-        let (id, sendfut) = self.inner.procedure_call(method, args);
+        let call =
+            args.and_then(|args| self.inner.procedure_call(method, args));
         async move {
-            let reqresp = sendfut.await?;
-            let text = reqresp.text().await?;
-            let respenv: ResponseEnvelope =
-                json::parse_value(json::parse_string(text)?)?;
-            let resp = respenv.unwrap(id)?;
-            Ok(resp)
+            let (id, request) =
+                call.map_err(|error| error.in_call(method, None))?;
+            retry::retrying(request.retry.clone(), || {
+                let body = request.send();
+                async move {
+                    let respenv: ResponseEnvelope =
+                        json::parse_value(body.await?)?;
+                    respenv.unwrap(id)
+                }
+            })
+            .await
+            .map_err(|error| error.in_call(method, Some(id)))
         }
     }
 }
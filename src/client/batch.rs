@@ -0,0 +1,115 @@
+//! Includes `Batch`, which sends several RPC requests in a single HTTP POST.
+use super::Client;
+use crate::wallet::Recipient;
+use crate::{
+    Address, BlockHash, HexBytes, Memo, Network, Rescan, ResponseResult, TxId,
+    ZecAmount,
+};
+use serde::de::DeserializeOwned;
+use std::future::Future;
+
+/// A `Batch` queues requests which all share the response type `R`, and sends them to `zcashd` as a single JSONRPC batch. Requests are queued by methods with the same names and parameters as the `Client` methods, and `send` resolves to one result per queued request, in the order they were queued.
+pub struct Batch<'a, R> {
+    client: &'a mut Client,
+    calls: Vec<(&'static str, ResponseResult<Vec<serde_json::Value>>)>,
+    response: std::marker::PhantomData<R>,
+}
+
+zcashrpc_macros::declare_rpc_batch_methods!("rpc_methods.json");
+
+impl<'a, R> Batch<'a, R>
+where
+    R: DeserializeOwned,
+{
+    pub(super) fn new(client: &'a mut Client) -> Self {
+        Batch {
+            client,
+            calls: Vec::new(),
+            response: std::marker::PhantomData,
+        }
+    }
+
+    /// The number of requests queued so far.
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    /// Whether no requests have been queued yet.
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    /// Send every queued request in one HTTP POST. The outer result reports failures of the batch as a whole, while each inner result reports the outcome of a single request, so an application-level error in one entry does not affect the others.
+    pub fn send(
+        self,
+    ) -> impl Future<Output = ResponseResult<Vec<ResponseResult<R>>>> {
+        use crate::{
+            envelope::ResponseEnvelope, error::JsonRpcViolation, json,
+        };
+        use std::collections::HashMap;
+
+        // Calls whose arguments were rejected client-side keep their error
+        // in place, and the rest are filled in from the server's responses.
+        let mut results = Vec::with_capacity(self.calls.len());
+        let mut valid_calls = Vec::with_capacity(self.calls.len());
+        for (method, args) in self.calls {
+            match args {
+                Ok(args) => {
+                    valid_calls.push((method, args));
+                    results.push(None);
+                }
+                Err(e) => results.push(Some(Err(e))),
+            }
+        }
+        // A batch of no calls is an invalid request to `zcashd`, so it isn't
+        // sent when every call was rejected.
+        let call = if valid_calls.is_empty() {
+            None
+        } else {
+            Some(self.client.inner.batch_call(valid_calls))
+        };
+        async move {
+            let (ids, request) = match call {
+                Some(call) => call?,
+                None => return Ok(results.into_iter().flatten().collect()),
+            };
+            let respenvs: Vec<ResponseEnvelope> =
+                super::retry::retrying(request.retry.clone(), || {
+                    let body = request.send();
+                    async move { json::parse_value(body.await?) }
+                })
+                .await?;
+            let mut by_id: HashMap<u64, ResponseEnvelope> = respenvs
+                .into_iter()
+                .map(|respenv| (respenv.id, respenv))
+                .collect();
+            let mut responses =
+                ids.into_iter().map(|id| match by_id.remove(&id) {
+                    Some(respenv) => respenv.unwrap(id),
+                    None => Err(JsonRpcViolation::MissingBatchResponse {
+                        client: id,
+                    }
+                    .into()),
+                });
+            Ok(results
+                .into_iter()
+                .map(|result| {
+                    result.unwrap_or_else(|| responses.next().unwrap())
+                })
+                .collect())
+        }
+    }
+
+    fn network(&self) -> Option<Network> {
+        self.client.network()
+    }
+
+    fn make_request(
+        &mut self,
+        method: &'static str,
+        args: ResponseResult<Vec<serde_json::Value>>,
+    ) -> &mut Self {
+        self.calls.push((method, args));
+        self
+    }
+}
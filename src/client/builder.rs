@@ -0,0 +1,148 @@
+//! Includes `ClientBuilder`, which configures how a `Client` reaches `zcashd`.
+use super::{retry::RetryPolicy, transport::HttpTransport, utils, Client};
+use crate::error::BuildError;
+use crate::{Auth, Network};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// A `ClientBuilder` configures the endpoint, authentication and HTTP behaviour of a `Client`, for `zcashd` nodes which aren't reached over plain HTTP at `http://hostport/`, such as those behind a TLS-terminating reverse proxy.
+pub struct ClientBuilder {
+    url: String,
+    auth: Auth,
+    headers: HeaderMap,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    root_certificates: Vec<reqwest::Certificate>,
+    reqcli: Option<reqwest::Client>,
+    network: Option<Network>,
+    retry: Option<RetryPolicy>,
+}
+
+impl ClientBuilder {
+    /// Begin building a `Client` which POSTs every request to `url`, an `http` or `https` URL which may include a path, e.g. `https://proxy.example/zcashd/`.
+    pub fn new(url: impl Into<String>) -> ClientBuilder {
+        ClientBuilder {
+            url: url.into(),
+            auth: Auth::None,
+            headers: HeaderMap::new(),
+            connect_timeout: None,
+            timeout: None,
+            root_certificates: Vec::new(),
+            reqcli: None,
+            network: None,
+            retry: None,
+        }
+    }
+
+    /// Authenticate with `auth`. Without it, no `Authorization` header is sent.
+    pub fn auth(mut self, auth: Auth) -> ClientBuilder {
+        self.auth = auth;
+        self
+    }
+
+    /// Send `name: value` with every request, e.g. a key expected by a proxy. A later value for the same `name` replaces an earlier one.
+    pub fn header(
+        mut self,
+        name: HeaderName,
+        value: HeaderValue,
+    ) -> ClientBuilder {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Give up on connecting to the node after `timeout`.
+    pub fn connect_timeout(mut self, timeout: Duration) -> ClientBuilder {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Give up on each request after `timeout`, from sending it until its response has been read.
+    pub fn timeout(mut self, timeout: Duration) -> ClientBuilder {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Trust `certificate` as a root when verifying an `https` endpoint, e.g. the CA of a proxy with a private certificate.
+    pub fn add_root_certificate(
+        mut self,
+        certificate: reqwest::Certificate,
+    ) -> ClientBuilder {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Send requests with `reqcli`, rather than with a `reqwest::Client` built from this builder's settings. Its own connection settings are used, so it can't be combined with `connect_timeout` or `add_root_certificate`.
+    pub fn reqwest_client(mut self, reqcli: reqwest::Client) -> ClientBuilder {
+        self.reqcli = Some(reqcli);
+        self
+    }
+
+    /// Declare which `Network` the node follows, as `Client::with_network` does.
+    pub fn network(mut self, network: Network) -> ClientBuilder {
+        self.network = Some(network);
+        self
+    }
+
+    /// Retry failed requests as `policy` allows, as `Client::with_retry` does.
+    pub fn retry(mut self, policy: RetryPolicy) -> ClientBuilder {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Build the `Client`, checking the URL and the HTTP settings.
+    pub fn build(mut self) -> Result<Client, BuildError> {
+        let network = self.network.take();
+        let retry = self.retry.take();
+        let mut inner =
+            utils::InnerCli::with_transport(Arc::new(self.build_transport()?));
+        inner.network = network;
+        inner.retry = retry.map(Arc::new);
+        Ok(Client { inner })
+    }
+
+    /// Build only the `HttpTransport` a `Client` would send requests with, e.g. to wrap it in a `RecordingTransport`. The `network` and `retry` settings belong to the `Client`, so they're ignored.
+    pub fn build_transport(self) -> Result<HttpTransport, BuildError> {
+        let url = reqwest::Url::parse(&self.url)?;
+        match url.scheme() {
+            "http" | "https" => (),
+            scheme => {
+                return Err(BuildError::UnsupportedScheme(scheme.to_string()))
+            }
+        }
+        let reqcli = match self.reqcli {
+            Some(reqcli) => {
+                if self.connect_timeout.is_some() {
+                    return Err(BuildError::ConflictsWithClient(
+                        "connect_timeout",
+                    ));
+                }
+                if !self.root_certificates.is_empty() {
+                    return Err(BuildError::ConflictsWithClient(
+                        "add_root_certificate",
+                    ));
+                }
+                reqcli
+            }
+            None => {
+                let mut builder = reqwest::Client::builder();
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                for certificate in self.root_certificates {
+                    builder = builder.add_root_certificate(certificate);
+                }
+                builder.build()?
+            }
+        };
+        Ok(HttpTransport {
+            url: String::from(url),
+            auth: Arc::new(Mutex::new(crate::auth::Credentials::new(
+                self.auth,
+            ))),
+            headers: self.headers,
+            timeout: self.timeout,
+            reqcli,
+        })
+    }
+}
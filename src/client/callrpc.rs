@@ -1,14 +1,33 @@
 macro_rules! rpc_call {
-    ( $self:ident . $rpcname:ident ( $( $arg:expr ),* ) ) => {
+    (
+        $self:ident . $rpcname:ident (
+            $( $arg:expr ),* $( ; $( $optarg:expr ),* )?
+        ) $( where $( $addr:ident ),* )?
+    ) => {
         {
-            let args = vec![
-                $(
-                    match serde_json::to_value($arg) {
-                        Ok(val) => val,
-                        Err(_) => panic!("Invalid arg passed to {}", stringify!($rpcname)),
-                    }
-                ),*
-            ];
+            let checked = crate::address::check_network(
+                $self.network(),
+                stringify!($rpcname),
+                vec![
+                    $($(
+                        (
+                            stringify!($addr),
+                            crate::address::AddressParam::addresses(&$addr),
+                        )
+                    ),*)?
+                ],
+            );
+            let args = checked.and_then(|()| crate::client::utils::build_params(
+                stringify!($rpcname),
+                vec![
+                    $( (stringify!($arg), serde_json::to_value($arg)) ),*
+                ],
+                vec![
+                    $($(
+                        (stringify!($optarg), $optarg.map(serde_json::to_value))
+                    ),*)?
+                ],
+            ));
 
             $self.make_request(stringify!($rpcname), args)
         }
@@ -0,0 +1,165 @@
+//! Includes `RetryPolicy`, which decides whether and when a `Client` sends a failed request again.
+use crate::Error;
+use std::collections::HashSet;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The methods a `RetryPolicy` doesn't retry unless `retry_method` is used, because sending them twice can have a different effect than sending them once, e.g. broadcasting a transaction or spending funds twice, or deriving an extra address.
+pub const NON_IDEMPOTENT: &[&str] = &[
+    "encryptwallet",
+    "generate",
+    "getnewaddress",
+    "getrawchangeaddress",
+    "sendfrom",
+    "sendmany",
+    "sendrawtransaction",
+    "sendtoaddress",
+    "stop",
+    "z_getnewaddress",
+    "z_mergetoaddress",
+    "z_sendmany",
+    "z_shieldcoinbase",
+];
+
+/// A `RetryPolicy` makes a `Client` send a request again when it fails with an error the policy's classifier considers transient, waiting an exponentially growing backoff between attempts. Requests to `NON_IDEMPOTENT` methods are sent only once unless `retry_method` allows them, and a batch is only retried if every method in it is allowed.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+    classifier: Arc<dyn Fn(&Error) -> bool + Send + Sync>,
+    excluded: HashSet<String>,
+}
+
+impl RetryPolicy {
+    /// A policy which makes at most 5 attempts, backing off from 100ms up to 10s with jitter, and retries the errors `is_transient` accepts.
+    pub fn new() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(10),
+            jitter: true,
+            classifier: Arc::new(RetryPolicy::is_transient),
+            excluded: NON_IDEMPOTENT.iter().map(|m| m.to_string()).collect(),
+        }
+    }
+
+    /// Send each request at most `max_attempts` times, including the first. An attempt count of 0 or 1 disables retries.
+    pub fn max_attempts(mut self, max_attempts: u32) -> RetryPolicy {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Wait `initial` before the first retry, and double the wait before each later retry, up to `max`.
+    pub fn backoff(mut self, initial: Duration, max: Duration) -> RetryPolicy {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// Whether to wait a random duration between half of and the whole backoff, so that clients which failed together don't all retry together. On by default.
+    pub fn jitter(mut self, jitter: bool) -> RetryPolicy {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Retry the errors for which `classifier` returns `true`, instead of those `is_transient` accepts.
+    pub fn classifier(
+        mut self,
+        classifier: impl Fn(&Error) -> bool + Send + Sync + 'static,
+    ) -> RetryPolicy {
+        self.classifier = Arc::new(classifier);
+        self
+    }
+
+    /// Allow requests to `method` to be retried, e.g. one of `NON_IDEMPOTENT` which the caller knows is safe to repeat.
+    pub fn retry_method(mut self, method: &str) -> RetryPolicy {
+        self.excluded.remove(method);
+        self
+    }
+
+    /// Never retry requests to `method`.
+    pub fn exclude_method(mut self, method: &str) -> RetryPolicy {
+        self.excluded.insert(method.to_string());
+        self
+    }
+
+    /// The default classifier, which accepts failures to connect, timeouts, a full work queue, a missing cookie file, which `zcashd` deletes when it shuts down and writes again when it starts, and the `RpcErrorCode::InWarmup` error `zcashd` responds with until it has loaded the chain.
+    pub fn is_transient(error: &Error) -> bool {
+        match error.inner() {
+            Error::Http(e) => e.is_connect() || e.is_timeout(),
+            Error::WorkQueueExceeded => true,
+            Error::Auth(e) => e.kind() == std::io::ErrorKind::NotFound,
+            _ => error.is_in_warmup(),
+        }
+    }
+
+    /// Whether this policy allows a request to `method` to be retried at all.
+    pub fn allows(&self, method: &str) -> bool {
+        self.max_attempts > 1 && !self.excluded.contains(method)
+    }
+
+    /// The wait before the retry which follows attempt number `attempt`, counting from 1.
+    fn delay(&self, attempt: u32) -> Duration {
+        let doublings = (attempt - 1).min(31);
+        let backoff = self
+            .initial_backoff
+            .checked_mul(1 << doublings)
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff));
+        if self.jitter {
+            backoff / 2 + backoff.mul_f64(random_fraction() / 2.0)
+        } else {
+            backoff
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy::new()
+    }
+}
+
+impl std::fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("initial_backoff", &self.initial_backoff)
+            .field("max_backoff", &self.max_backoff)
+            .field("jitter", &self.jitter)
+            .field("excluded", &self.excluded)
+            .finish()
+    }
+}
+
+/// Make attempts until one succeeds, fails with an error `policy` doesn't retry, or `policy` runs out of attempts. Without a policy, only one attempt is made.
+pub(crate) async fn retrying<T, F, Fut>(
+    policy: Option<Arc<RetryPolicy>>,
+    mut attempt: F,
+) -> Result<T, Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, Error>>,
+{
+    let mut attempts = 1;
+    loop {
+        match (attempt().await, &policy) {
+            (Err(e), Some(policy))
+                if attempts < policy.max_attempts
+                    && (policy.classifier)(&e) =>
+            {
+                tokio::time::delay_for(policy.delay(attempts)).await;
+                attempts += 1;
+            }
+            (result, _) => return result,
+        }
+    }
+}
+
+/// A random number in `[0, 1)`.
+fn random_fraction() -> f64 {
+    let bits = super::utils::random_u64() >> 11;
+    bits as f64 / (1u64 << 53) as f64
+}
@@ -0,0 +1,1386 @@
+//procedurally generated response types, note that zcashrpc-typegen
+//is in early alpha, and output is subject to change at any time.
+pub mod addmultisigaddress {
+    pub type AddmultisigaddressResponse = String;
+}
+pub mod addnode {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct AddnodeResponse;
+}
+pub mod backupwallet {
+    pub type BackupwalletResponse = String;
+}
+pub mod clearbanned {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ClearbannedResponse;
+}
+pub mod createmultisig {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct CreatemultisigResponse {
+        pub address: crate::Address,
+        #[serde(rename = "redeemScript")]
+        pub redeem_script: crate::HexBytes,
+    }
+}
+pub mod createrawtransaction {
+    pub type CreaterawtransactionResponse = String;
+}
+pub mod decoderawtransaction {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct DecoderawtransactionResponse {
+        pub expiryheight: Option<u32>,
+        pub versiongroupid: Option<String>,
+        pub locktime: u32,
+        pub overwintered: bool,
+        pub size: u64,
+        pub txid: crate::TxId,
+        pub version: u32,
+        pub vin: Vec<Vin>,
+        pub vjoinsplit: Vec<Vjoinsplit>,
+        pub vout: Vec<Vout>,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ScriptPubKey {
+        pub addresses: Option<Vec<crate::Address>>,
+        pub asm: String,
+        pub hex: crate::HexBytes,
+        #[serde(rename = "reqSigs")]
+        pub req_sigs: Option<u32>,
+        #[serde(rename = "type")]
+        pub type_field: String,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ScriptSig {
+        pub asm: String,
+        pub hex: crate::HexBytes,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Vin {
+        pub coinbase: Option<crate::HexBytes>,
+        #[serde(rename = "scriptSig")]
+        pub script_sig: Option<ScriptSig>,
+        pub sequence: u32,
+        pub txid: Option<crate::TxId>,
+        pub vout: Option<u32>,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Vjoinsplit {
+        pub anchor: crate::MerkleRoot,
+        pub ciphertexts: Vec<String>,
+        pub commitments: Vec<String>,
+        pub macs: Vec<String>,
+        pub nullifiers: Vec<String>,
+        #[serde(rename = "onetimePubKey")]
+        pub onetime_pub_key: String,
+        pub proof: String,
+        #[serde(rename = "randomSeed")]
+        pub random_seed: String,
+        pub vpub_new: crate::ZecAmount,
+        pub vpub_old: crate::ZecAmount,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Vout {
+        pub n: u32,
+        #[serde(rename = "scriptPubKey")]
+        pub script_pub_key: ScriptPubKey,
+        pub value: crate::ZecAmount,
+    }
+}
+pub mod decodescript {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct DecodescriptResponse {
+        pub addresses: Vec<crate::Address>,
+        pub asm: String,
+        pub hex: crate::HexBytes,
+        pub p2sh: crate::Address,
+        #[serde(rename = "reqSigs")]
+        pub req_sigs: u32,
+        #[serde(rename = "type")]
+        pub type_field: String,
+    }
+}
+pub mod disconnectnode {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct DisconnectnodeResponse;
+}
+pub mod dumpprivkey {
+    pub type DumpprivkeyResponse = String;
+}
+pub mod dumpwallet {
+    pub type DumpwalletResponse = String;
+}
+pub mod encryptwallet {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct EncryptwalletResponse;
+}
+pub mod estimatefee {
+    pub type EstimatefeeResponse = crate::ZecAmount;
+}
+pub mod estimatepriority {
+    pub type EstimatepriorityResponse = rust_decimal::Decimal;
+}
+pub mod fundrawtransaction {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct FundrawtransactionResponse {
+        pub changepos: u32,
+        pub fee: crate::ZecAmount,
+        pub hex: crate::HexBytes,
+    }
+}
+pub mod generate {
+    pub type GenerateResponse = Vec<String>;
+}
+pub mod getaccount {
+    pub type GetaccountResponse = String;
+}
+pub mod getaccountaddress {
+    pub type GetaccountaddressResponse = crate::Address;
+}
+pub mod getaddednodeinfo {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Addresses {
+        pub address: String,
+        pub connected: String,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Getaddednodeinfo {
+        pub addednode: String,
+        pub addresses: Vec<Addresses>,
+        pub connected: bool,
+    }
+    pub type GetaddednodeinfoResponse = Vec<Getaddednodeinfo>;
+}
+pub mod getaddressbalance {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct GetaddressbalanceResponse {
+        pub balance: String,
+        pub received: String,
+    }
+}
+pub mod getaddressdeltas {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    #[serde(untagged)]
+    pub enum GetaddressdeltasResponse {
+        Regular(Vec<Regular>),
+        Verbose(Verbose),
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Deltas {
+        pub address: crate::Address,
+        pub height: u32,
+        pub index: u32,
+        pub satoshis: crate::Zatoshis,
+        pub txid: crate::TxId,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct End {
+        pub hash: crate::BlockHash,
+        pub height: u32,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Regular {
+        pub address: crate::Address,
+        pub height: u32,
+        pub index: u32,
+        pub satoshis: crate::Zatoshis,
+        pub txid: crate::TxId,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Start {
+        pub hash: crate::BlockHash,
+        pub height: u32,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Verbose {
+        pub deltas: Vec<Deltas>,
+        pub end: End,
+        pub start: Start,
+    }
+}
+pub mod getaddressesbyaccount {
+    pub type GetaddressesbyaccountResponse = Vec<crate::Address>;
+}
+pub mod getaddressmempool {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Getaddressmempool {
+        pub address: crate::Address,
+        pub index: u32,
+        pub prevout: String,
+        pub prevtxid: crate::TxId,
+        pub satoshis: crate::Zatoshis,
+        pub timestamp: u64,
+        pub txid: crate::TxId,
+    }
+    pub type GetaddressmempoolResponse = Vec<Getaddressmempool>;
+}
+pub mod getaddresstxids {
+    pub type GetaddresstxidsResponse = Vec<String>;
+}
+pub mod getaddressutxos {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    #[serde(untagged)]
+    pub enum GetaddressutxosResponse {
+        Regular(Vec<Regular>),
+        Verbose(Verbose),
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Regular {
+        pub address: crate::Address,
+        pub height: u32,
+        #[serde(rename = "outputIndex")]
+        pub output_index: u32,
+        pub satoshis: crate::Zatoshis,
+        pub script: crate::HexBytes,
+        pub txid: crate::TxId,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Utxos {
+        pub address: crate::Address,
+        pub height: u32,
+        #[serde(rename = "outputIndex")]
+        pub output_index: u32,
+        pub satoshis: crate::Zatoshis,
+        pub script: crate::HexBytes,
+        pub txid: crate::TxId,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Verbose {
+        pub hash: crate::BlockHash,
+        pub height: u32,
+        pub utxos: Vec<Utxos>,
+    }
+}
+pub mod getbalance {
+    pub type GetbalanceResponse = crate::ZecAmount;
+}
+pub mod getbestblockhash {
+    pub type GetbestblockhashResponse = crate::BlockHash;
+}
+pub mod getblock {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    #[serde(untagged)]
+    pub enum GetblockResponse {
+        Regular(String),
+        Verbose(Verbose),
+        VeryVerbose(VeryVerbose),
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ScriptPubKey {
+        pub addresses: Option<Vec<crate::Address>>,
+        pub asm: String,
+        pub hex: crate::HexBytes,
+        #[serde(rename = "reqSigs")]
+        pub req_sigs: Option<u32>,
+        #[serde(rename = "type")]
+        pub type_field: String,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ScriptSig {
+        pub asm: String,
+        pub hex: crate::HexBytes,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Tx {
+        pub expiryheight: Option<u32>,
+        pub blockhash: Option<crate::BlockHash>,
+        pub blocktime: Option<u64>,
+        pub confirmations: Option<i64>,
+        pub hex: Option<crate::HexBytes>,
+        pub in_active_chain: Option<bool>,
+        pub locktime: u32,
+        pub size: u64,
+        pub time: Option<u64>,
+        pub txid: crate::TxId,
+        pub version: u32,
+        pub vin: Vec<Vin>,
+        pub vjoinsplit: Vec<Vjoinsplit>,
+        pub vout: Vec<Vout>,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Verbose {
+        pub bits: String,
+        pub confirmations: i64,
+        pub difficulty: rust_decimal::Decimal,
+        pub finalsaplingroot: crate::MerkleRoot,
+        pub hash: crate::BlockHash,
+        pub height: u32,
+        pub merkleroot: crate::MerkleRoot,
+        pub nextblockhash: Option<crate::BlockHash>,
+        pub nonce: crate::HexBytes,
+        pub previousblockhash: Option<crate::BlockHash>,
+        pub size: u64,
+        pub time: u64,
+        pub tx: Vec<crate::TxId>,
+        pub version: u32,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct VeryVerbose {
+        pub bits: String,
+        pub confirmations: i64,
+        pub difficulty: rust_decimal::Decimal,
+        pub finalsaplingroot: crate::MerkleRoot,
+        pub hash: crate::BlockHash,
+        pub height: u32,
+        pub merkleroot: crate::MerkleRoot,
+        pub nextblockhash: Option<crate::BlockHash>,
+        pub nonce: crate::HexBytes,
+        pub previousblockhash: Option<crate::BlockHash>,
+        pub size: u64,
+        pub time: u64,
+        pub tx: Vec<Tx>,
+        pub version: u32,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Vin {
+        pub coinbase: Option<crate::HexBytes>,
+        #[serde(rename = "scriptSig")]
+        pub script_sig: Option<ScriptSig>,
+        pub sequence: u32,
+        pub txid: Option<crate::TxId>,
+        pub vout: Option<u32>,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Vjoinsplit {
+        pub anchor: crate::MerkleRoot,
+        pub ciphertexts: Vec<String>,
+        pub commitments: Vec<String>,
+        pub macs: Vec<String>,
+        pub nullifiers: Vec<String>,
+        #[serde(rename = "onetimePubKey")]
+        pub onetime_pub_key: String,
+        pub proof: String,
+        #[serde(rename = "randomSeed")]
+        pub random_seed: String,
+        pub vpub_new: crate::ZecAmount,
+        pub vpub_old: crate::ZecAmount,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Vout {
+        pub n: u32,
+        #[serde(rename = "scriptPubKey")]
+        pub script_pub_key: ScriptPubKey,
+        pub value: crate::ZecAmount,
+    }
+}
+pub mod getblockchaininfo {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Consensus {
+        pub chaintip: String,
+        pub nextblock: String,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Enforce {
+        pub found: u32,
+        pub required: u32,
+        pub status: bool,
+        pub window: u32,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct GetblockchaininfoResponse {
+        pub bestblockhash: crate::BlockHash,
+        pub blocks: u32,
+        pub chain: crate::Network,
+        pub chainwork: String,
+        pub commitments: u64,
+        pub consensus: Consensus,
+        pub difficulty: rust_decimal::Decimal,
+        pub estimatedheight: u32,
+        pub headers: u32,
+        pub initial_block_download_complete: Option<bool>,
+        pub size_on_disk: u64,
+        pub softforks: Vec<Softforks>,
+        pub upgrades: std::collections::HashMap<String, Upgrades>,
+        pub verificationprogress: rust_decimal::Decimal,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Reject {
+        pub found: u32,
+        pub required: u32,
+        pub status: bool,
+        pub window: u32,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Softforks {
+        pub enforce: Enforce,
+        pub id: String,
+        pub reject: Reject,
+        pub version: u32,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Upgrades {
+        pub activationheight: u32,
+        pub info: String,
+        pub name: String,
+        pub status: String,
+    }
+}
+pub mod getblockcount {
+    pub type GetblockcountResponse = u32;
+}
+pub mod getblockdeltas {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Deltas {
+        pub index: u32,
+        pub inputs: Vec<Inputs>,
+        pub outputs: Vec<Outputs>,
+        pub txid: crate::TxId,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct GetblockdeltasResponse {
+        pub bits: String,
+        pub chainwork: String,
+        pub confirmations: i64,
+        pub deltas: Vec<Deltas>,
+        pub difficulty: rust_decimal::Decimal,
+        pub hash: crate::BlockHash,
+        pub height: u32,
+        pub mediantime: u64,
+        pub merkleroot: crate::MerkleRoot,
+        pub nextblockhash: Option<crate::BlockHash>,
+        pub nonce: String,
+        pub previousblockhash: Option<crate::BlockHash>,
+        pub size: u64,
+        pub time: u64,
+        pub version: u32,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Inputs {
+        pub address: crate::Address,
+        pub index: u32,
+        pub prevout: u32,
+        pub prevtxid: crate::TxId,
+        pub satoshis: crate::Zatoshis,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Outputs {
+        pub address: crate::Address,
+        pub index: u32,
+        pub satoshis: crate::Zatoshis,
+    }
+}
+pub mod getblockhash {
+    pub type GetblockhashResponse = crate::BlockHash;
+}
+pub mod getblockhashes {
+    pub type GetblockhashesResponse = Vec<String>;
+}
+pub mod getblockheader {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    #[serde(untagged)]
+    pub enum GetblockheaderResponse {
+        Regular(String),
+        Verbose(Verbose),
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Verbose {
+        pub bits: String,
+        pub confirmations: i64,
+        pub difficulty: rust_decimal::Decimal,
+        pub finalsaplingroot: crate::MerkleRoot,
+        pub hash: crate::BlockHash,
+        pub height: u32,
+        pub merkleroot: crate::MerkleRoot,
+        pub nextblockhash: Option<crate::BlockHash>,
+        pub nonce: crate::HexBytes,
+        pub previousblockhash: Option<crate::BlockHash>,
+        pub time: u64,
+        pub version: u32,
+    }
+}
+pub mod getblocksubsidy {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Fundingstreams {
+        pub address: crate::Address,
+        pub recipient: String,
+        pub specification: String,
+        pub value: crate::ZecAmount,
+        #[serde(rename = "valueZat")]
+        pub value_zat: crate::Zatoshis,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct GetblocksubsidyResponse {
+        pub founders: crate::ZecAmount,
+        pub fundingstreams: Vec<Fundingstreams>,
+        pub miner: crate::ZecAmount,
+    }
+}
+pub mod getchaintips {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Getchaintips {
+        pub branchlen: u32,
+        pub hash: crate::BlockHash,
+        pub height: u32,
+        pub status: String,
+    }
+    pub type GetchaintipsResponse = Vec<Getchaintips>;
+}
+pub mod getconnectioncount {
+    pub type GetconnectioncountResponse = u32;
+}
+pub mod getdeprecationinfo {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct GetdeprecationinfoResponse {
+        pub deprecationheight: u32,
+        pub subversion: String,
+        pub version: u32,
+    }
+}
+pub mod getdifficulty {
+    pub type GetdifficultyResponse = rust_decimal::Decimal;
+}
+pub mod getexperimentalfeatures {
+    pub type GetexperimentalfeaturesResponse = Vec<String>;
+}
+pub mod getgenerate {
+    pub type GetgenerateResponse = bool;
+}
+pub mod getinfo {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct GetinfoResponse {
+        pub proxy: Option<String>,
+        pub balance: crate::ZecAmount,
+        pub blocks: u32,
+        pub connections: u32,
+        pub difficulty: rust_decimal::Decimal,
+        pub errors: String,
+        pub keypoololdest: u64,
+        pub keypoolsize: u32,
+        pub paytxfee: crate::ZecAmount,
+        pub protocolversion: u32,
+        pub relayfee: crate::ZecAmount,
+        pub testnet: bool,
+        pub timeoffset: i64,
+        pub unlocked_until: Option<u64>,
+        pub version: u32,
+        pub walletversion: u32,
+    }
+}
+pub mod getlocalsolps {
+    pub type GetlocalsolpsResponse = rust_decimal::Decimal;
+}
+pub mod getmemoryinfo {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct GetmemoryinfoResponse {
+        pub locked: Locked,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Locked {
+        pub chunks_free: u64,
+        pub chunks_used: u64,
+        pub free: u64,
+        pub locked: u64,
+        pub total: rust_decimal::Decimal,
+        pub used: u64,
+    }
+}
+pub mod getmempoolinfo {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct GetmempoolinfoResponse {
+        pub bytes: u64,
+        pub size: u64,
+        pub usage: u64,
+    }
+}
+pub mod getmininginfo {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct GetmininginfoResponse {
+        pub blocks: u32,
+        pub chain: crate::Network,
+        pub currentblocksize: u64,
+        pub currentblocktx: u32,
+        pub difficulty: rust_decimal::Decimal,
+        pub errors: String,
+        pub generate: bool,
+        pub genproclimit: i64,
+        pub localsolps: rust_decimal::Decimal,
+        pub networksolps: rust_decimal::Decimal,
+        pub pooledtx: u32,
+        pub testnet: bool,
+    }
+}
+pub mod getnettotals {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct GetnettotalsResponse {
+        pub timemillis: u64,
+        pub totalbytesrecv: u64,
+        pub totalbytessent: u64,
+        pub uploadtarget: Uploadtarget,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Uploadtarget {
+        pub bytes_left_in_cycle: u64,
+        pub serve_historical_blocks: bool,
+        pub target: u64,
+        pub target_reached: bool,
+        pub time_left_in_cycle: u64,
+        pub timeframe: u64,
+    }
+}
+pub mod getnetworkhashps {
+    pub type GetnetworkhashpsResponse = rust_decimal::Decimal;
+}
+pub mod getnetworkinfo {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct GetnetworkinfoResponse {
+        pub connections: u32,
+        pub localaddresses: Vec<Localaddresses>,
+        pub localservices: String,
+        pub networks: Vec<Networks>,
+        pub protocolversion: u32,
+        pub relayfee: crate::ZecAmount,
+        pub subversion: String,
+        pub timeoffset: i64,
+        pub version: u32,
+        pub warnings: String,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Localaddresses {
+        pub address: String,
+        pub port: u32,
+        pub score: u32,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Networks {
+        pub limited: bool,
+        pub name: String,
+        pub proxy: String,
+        pub reachable: bool,
+    }
+}
+pub mod getnetworksolps {
+    pub type GetnetworksolpsResponse = rust_decimal::Decimal;
+}
+pub mod getnewaddress {
+    pub type GetnewaddressResponse = crate::Address;
+}
+pub mod getpeerinfo {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Getpeerinfo {
+        pub addr: String,
+        pub addrlocal: String,
+        pub banscore: i64,
+        pub bytesrecv: u64,
+        pub bytessent: u64,
+        pub conntime: u64,
+        pub id: u64,
+        pub inbound: bool,
+        pub inflight: Vec<rust_decimal::Decimal>,
+        pub lastrecv: u64,
+        pub lastsend: u64,
+        pub pingtime: rust_decimal::Decimal,
+        pub pingwait: rust_decimal::Decimal,
+        pub services: String,
+        pub startingheight: u32,
+        pub subver: String,
+        pub synced_blocks: i64,
+        pub synced_headers: i64,
+        pub timeoffset: i64,
+        pub version: u32,
+    }
+    pub type GetpeerinfoResponse = Vec<Getpeerinfo>;
+}
+pub mod getrawchangeaddress {
+    pub type GetrawchangeaddressResponse = crate::Address;
+}
+pub mod getrawmempool {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    #[serde(untagged)]
+    pub enum GetrawmempoolResponse {
+        Regular(Vec<String>),
+        Verbose(std::collections::HashMap<String, Verbose>),
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Verbose {
+        pub currentpriority: rust_decimal::Decimal,
+        pub depends: Vec<crate::TxId>,
+        pub fee: crate::ZecAmount,
+        pub height: u32,
+        pub size: u64,
+        pub startingpriority: rust_decimal::Decimal,
+        pub time: u64,
+    }
+}
+pub mod getrawtransaction {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    #[serde(untagged)]
+    pub enum GetrawtransactionResponse {
+        Regular(String),
+        Verbose(Verbose),
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ScriptPubKey {
+        pub addresses: Option<Vec<crate::Address>>,
+        pub asm: String,
+        pub hex: crate::HexBytes,
+        #[serde(rename = "reqSigs")]
+        pub req_sigs: Option<u32>,
+        #[serde(rename = "type")]
+        pub type_field: String,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ScriptSig {
+        pub asm: String,
+        pub hex: crate::HexBytes,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Verbose {
+        pub expiryheight: Option<u32>,
+        pub blockhash: Option<crate::BlockHash>,
+        pub blocktime: Option<u64>,
+        pub confirmations: Option<i64>,
+        pub hex: crate::HexBytes,
+        pub in_active_chain: Option<bool>,
+        pub locktime: u32,
+        pub size: u64,
+        pub time: Option<u64>,
+        pub txid: crate::TxId,
+        pub version: u32,
+        pub vin: Vec<Vin>,
+        pub vjoinsplit: Vec<Vjoinsplit>,
+        pub vout: Vec<Vout>,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Vin {
+        pub coinbase: Option<crate::HexBytes>,
+        #[serde(rename = "scriptSig")]
+        pub script_sig: Option<ScriptSig>,
+        pub sequence: u32,
+        pub txid: Option<crate::TxId>,
+        pub vout: Option<u32>,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Vjoinsplit {
+        pub anchor: crate::MerkleRoot,
+        pub ciphertexts: Vec<String>,
+        pub commitments: Vec<String>,
+        pub macs: Vec<String>,
+        pub nullifiers: Vec<String>,
+        #[serde(rename = "onetimePubKey")]
+        pub onetime_pub_key: String,
+        pub proof: String,
+        #[serde(rename = "randomSeed")]
+        pub random_seed: String,
+        pub vpub_new: crate::ZecAmount,
+        pub vpub_old: crate::ZecAmount,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Vout {
+        pub n: u32,
+        #[serde(rename = "scriptPubKey")]
+        pub script_pub_key: ScriptPubKey,
+        pub value: crate::ZecAmount,
+    }
+}
+pub mod getreceivedbyaccount {
+    pub type GetreceivedbyaccountResponse = crate::ZecAmount;
+}
+pub mod getreceivedbyaddress {
+    pub type GetreceivedbyaddressResponse = crate::ZecAmount;
+}
+pub mod getspentinfo {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct GetspentinfoResponse {
+        pub index: u32,
+        pub txid: crate::TxId,
+    }
+}
+pub mod gettransaction {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Details {
+        pub account: String,
+        pub address: crate::Address,
+        pub amount: crate::ZecAmount,
+        #[serde(rename = "amountZat")]
+        pub amount_zat: crate::Zatoshis,
+        pub category: String,
+        pub vout: u32,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct GettransactionResponse {
+        pub amount: crate::ZecAmount,
+        #[serde(rename = "amountZat")]
+        pub amount_zat: crate::Zatoshis,
+        pub blockhash: Option<crate::BlockHash>,
+        pub blockindex: u32,
+        pub blocktime: Option<u64>,
+        pub confirmations: i64,
+        pub details: Vec<Details>,
+        pub hex: crate::HexBytes,
+        pub status: String,
+        pub time: u64,
+        pub timereceived: u64,
+        pub txid: crate::TxId,
+        pub vjoinsplit: Vec<Vjoinsplit>,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Vjoinsplit {
+        pub anchor: crate::MerkleRoot,
+        pub commitments: Vec<String>,
+        pub macs: Vec<String>,
+        pub nullifiers: Vec<String>,
+        pub vpub_new: crate::ZecAmount,
+        pub vpub_old: crate::ZecAmount,
+    }
+}
+pub mod gettxout {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct GettxoutResponse {
+        pub bestblock: crate::BlockHash,
+        pub coinbase: bool,
+        pub confirmations: i64,
+        #[serde(rename = "scriptPubKey")]
+        pub script_pub_key: ScriptPubKey,
+        pub value: crate::ZecAmount,
+        pub version: u32,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ScriptPubKey {
+        pub addresses: Option<Vec<crate::Address>>,
+        pub asm: String,
+        pub hex: crate::HexBytes,
+        #[serde(rename = "reqSigs")]
+        pub req_sigs: Option<u32>,
+        #[serde(rename = "type")]
+        pub type_field: String,
+    }
+}
+pub mod gettxoutproof {
+    pub type GettxoutproofResponse = String;
+}
+pub mod gettxoutsetinfo {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct GettxoutsetinfoResponse {
+        pub bestblock: crate::BlockHash,
+        pub bytes_serialized: u64,
+        pub hash_serialized: String,
+        pub height: u32,
+        pub total_amount: crate::ZecAmount,
+        pub transactions: u64,
+        pub txouts: u64,
+    }
+}
+pub mod getunconfirmedbalance {
+    pub type GetunconfirmedbalanceResponse = crate::ZecAmount;
+}
+pub mod getwalletinfo {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct GetwalletinfoResponse {
+        pub balance: crate::ZecAmount,
+        pub immature_balance: crate::ZecAmount,
+        pub keypoololdest: u64,
+        pub keypoolsize: u32,
+        pub paytxfee: crate::ZecAmount,
+        pub seedfp: String,
+        pub shielded_balance: crate::ZecAmount,
+        pub shielded_unconfirmed_balance: crate::ZecAmount,
+        pub txcount: u64,
+        pub unconfirmed_balance: crate::ZecAmount,
+        pub unlocked_until: Option<u64>,
+        pub walletversion: u32,
+    }
+}
+pub mod help {
+    pub type HelpResponse = String;
+}
+pub mod importaddress {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ImportaddressResponse;
+}
+pub mod importprivkey {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ImportprivkeyResponse;
+}
+pub mod importpubkey {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ImportpubkeyResponse;
+}
+pub mod importwallet {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ImportwalletResponse;
+}
+pub mod keypoolrefill {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct KeypoolrefillResponse;
+}
+pub mod listaccounts {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ListaccountsResponse {
+        pub account: crate::ZecAmount,
+    }
+}
+pub mod listaddressgroupings {
+    pub type ListaddressgroupingsResponse = Vec<Vec<Vec<String>>>;
+}
+pub mod listbanned {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ListbannedResponse;
+}
+pub mod listlockunspent {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Listlockunspent {
+        pub txid: crate::TxId,
+        pub vout: u32,
+    }
+    pub type ListlockunspentResponse = Vec<Listlockunspent>;
+}
+pub mod listreceivedbyaccount {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Listreceivedbyaccount {
+        pub account: String,
+        pub amount: crate::ZecAmount,
+        #[serde(rename = "amountZat")]
+        pub amount_zat: crate::Zatoshis,
+        pub confirmations: i64,
+        #[serde(rename = "involvesWatchonly")]
+        pub involves_watchonly: bool,
+    }
+    pub type ListreceivedbyaccountResponse = Vec<Listreceivedbyaccount>;
+}
+pub mod listreceivedbyaddress {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Listreceivedbyaddress {
+        pub account: String,
+        pub address: crate::Address,
+        pub amount: crate::ZecAmount,
+        #[serde(rename = "amountZat")]
+        pub amount_zat: crate::Zatoshis,
+        pub confirmations: i64,
+        #[serde(rename = "involvesWatchonly")]
+        pub involves_watchonly: bool,
+    }
+    pub type ListreceivedbyaddressResponse = Vec<Listreceivedbyaddress>;
+}
+pub mod listsinceblock {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ListsinceblockResponse {
+        pub lastblock: crate::BlockHash,
+        pub transactions: Vec<String>,
+    }
+}
+pub mod listtransactions {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Listtransactions {
+        pub account: String,
+        pub address: crate::Address,
+        pub amount: crate::ZecAmount,
+        #[serde(rename = "amountZat")]
+        pub amount_zat: crate::Zatoshis,
+        pub blockhash: Option<crate::BlockHash>,
+        pub blockindex: u32,
+        pub category: String,
+        pub comment: String,
+        pub confirmations: i64,
+        pub fee: crate::ZecAmount,
+        pub otheraccount: String,
+        pub size: u64,
+        pub status: String,
+        pub time: u64,
+        pub timereceived: u64,
+        pub txid: crate::TxId,
+        pub vout: u32,
+    }
+    pub type ListtransactionsResponse = Vec<Listtransactions>;
+}
+pub mod listunspent {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Listunspent {
+        pub account: String,
+        pub address: crate::Address,
+        pub amount: crate::ZecAmount,
+        #[serde(rename = "amountZat")]
+        pub amount_zat: crate::Zatoshis,
+        pub confirmations: i64,
+        pub generated: bool,
+        #[serde(rename = "redeemScript")]
+        pub redeem_script: crate::HexBytes,
+        #[serde(rename = "scriptPubKey")]
+        pub script_pub_key: crate::HexBytes,
+        pub spendable: bool,
+        pub txid: crate::TxId,
+        pub vout: u32,
+    }
+    pub type ListunspentResponse = Vec<Listunspent>;
+}
+pub mod lockunspent {
+    pub type LockunspentResponse = bool;
+}
+pub mod move_mod {
+    pub type MoveResponse = bool;
+}
+pub mod ping {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct PingResponse;
+}
+pub mod prioritisetransaction {
+    pub type PrioritisetransactionResponse = bool;
+}
+pub mod sendfrom {
+    pub type SendfromResponse = String;
+}
+pub mod sendmany {
+    pub type SendmanyResponse = String;
+}
+pub mod sendrawtransaction {
+    pub type SendrawtransactionResponse = crate::TxId;
+}
+pub mod sendtoaddress {
+    pub type SendtoaddressResponse = String;
+}
+pub mod setaccount {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct SetaccountResponse;
+}
+pub mod setban {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct SetbanResponse;
+}
+pub mod setgenerate {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct SetgenerateResponse;
+}
+pub mod setlogfilter {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct SetlogfilterResponse;
+}
+pub mod settxfee {
+    pub type SettxfeeResponse = bool;
+}
+pub mod signmessage {
+    pub type SignmessageResponse = String;
+}
+pub mod signrawtransaction {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Errors {
+        pub error: String,
+        #[serde(rename = "scriptSig")]
+        pub script_sig: String,
+        pub sequence: u32,
+        pub txid: crate::TxId,
+        pub vout: u32,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct SignrawtransactionResponse {
+        pub complete: bool,
+        pub errors: Vec<Errors>,
+        pub hex: crate::HexBytes,
+    }
+}
+pub mod stop {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct StopResponse;
+}
+pub mod submitblock {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub enum SubmitblockResponse {
+        #[serde(rename = "duplicate")]
+        Duplicate,
+        #[serde(rename = "duplicate-invalid")]
+        DuplicateInvalid,
+        #[serde(rename = "duplicate-inconclusive")]
+        DuplicateInconclusive,
+        #[serde(rename = "inconclusive")]
+        Inconclusive,
+        #[serde(rename = "rejected")]
+        Rejected,
+    }
+}
+pub mod validateaddress {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ValidateaddressResponse {
+        pub account: String,
+        pub address: crate::Address,
+        pub iscompressed: bool,
+        pub ismine: bool,
+        pub isscript: bool,
+        pub isvalid: bool,
+        pub pubkey: crate::HexBytes,
+        #[serde(rename = "scriptPubKey")]
+        pub script_pub_key: crate::HexBytes,
+    }
+}
+pub mod verifychain {
+    pub type VerifychainResponse = bool;
+}
+pub mod verifymessage {
+    pub type VerifymessageResponse = bool;
+}
+pub mod verifytxoutproof {
+    pub type VerifytxoutproofResponse = Vec<String>;
+}
+pub mod z_exportkey {
+    pub type ZExportkeyResponse = String;
+}
+pub mod z_exportviewingkey {
+    pub type ZExportviewingkeyResponse = String;
+}
+pub mod z_exportwallet {
+    pub type ZExportwalletResponse = String;
+}
+pub mod z_getbalance {
+    pub type ZGetbalanceResponse = crate::ZecAmount;
+}
+pub mod z_getmigrationstatus {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ZGetmigrationstatusResponse {
+        pub time_started: Option<u64>,
+        pub destination_address: crate::Address,
+        pub enabled: bool,
+        pub finalized_migrated_amount: crate::ZecAmount,
+        pub finalized_migration_transactions: u32,
+        pub migration_txids: Vec<crate::TxId>,
+        pub unfinalized_migrated_amount: crate::ZecAmount,
+        pub unmigrated_amount: crate::ZecAmount,
+    }
+}
+pub mod z_getnewaddress {
+    pub type ZGetnewaddressResponse = crate::Address;
+}
+pub mod z_getnotescount {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ZGetnotescountResponse {
+        pub sapling: u32,
+        pub sprout: u32,
+    }
+}
+pub mod z_getpaymentdisclosure {
+    pub type ZGetpaymentdisclosureResponse = String;
+}
+pub mod z_gettotalbalance {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ZGettotalbalanceResponse {
+        pub private: crate::ZecAmount,
+        pub total: crate::ZecAmount,
+        pub transparent: crate::ZecAmount,
+    }
+}
+pub mod z_gettreestate {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Commitments {
+        #[serde(rename = "finalRoot")]
+        pub final_root: crate::MerkleRoot,
+        #[serde(rename = "finalState")]
+        pub final_state: Option<crate::HexBytes>,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Sapling {
+        pub commitments: Commitments,
+        #[serde(rename = "skipHash")]
+        pub skip_hash: Option<crate::BlockHash>,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Sprout {
+        pub commitments: Commitments,
+        #[serde(rename = "skipHash")]
+        pub skip_hash: Option<crate::BlockHash>,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ZGettreestateResponse {
+        pub hash: crate::BlockHash,
+        pub height: u32,
+        pub sapling: Sapling,
+        pub sprout: Sprout,
+    }
+}
+pub mod z_importkey {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ZImportkeyResponse {
+        pub address: crate::Address,
+        #[serde(rename = "type")]
+        pub type_field: String,
+    }
+}
+pub mod z_importviewingkey {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ZImportviewingkeyResponse {
+        pub address: crate::Address,
+        #[serde(rename = "type")]
+        pub type_field: String,
+    }
+}
+pub mod z_importwallet {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ZImportwalletResponse;
+}
+pub mod z_listaddresses {
+    pub type ZListaddressesResponse = Vec<crate::Address>;
+}
+pub mod z_listoperationids {
+    pub type ZListoperationidsResponse = Vec<String>;
+}
+pub mod z_listreceivedbyaddress {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ZListreceivedbyaddress {
+        pub amount: crate::ZecAmount,
+        #[serde(rename = "amountZat")]
+        pub amount_zat: crate::Zatoshis,
+        pub blockheight: u32,
+        pub blockindex: u32,
+        pub blocktime: Option<u64>,
+        pub change: bool,
+        pub confirmations: i64,
+        pub jsindex: Option<u32>,
+        pub jsoutindex: Option<u32>,
+        pub memo: crate::Memo,
+        pub outindex: Option<u32>,
+        pub txid: crate::TxId,
+    }
+    pub type ZListreceivedbyaddressResponse = Vec<ZListreceivedbyaddress>;
+}
+pub mod z_listunspent {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ZListunspent {
+        pub address: crate::Address,
+        pub amount: crate::ZecAmount,
+        pub change: bool,
+        pub confirmations: i64,
+        pub jsindex: Option<u32>,
+        pub jsoutindex: Option<u32>,
+        pub memo: crate::Memo,
+        pub outindex: Option<u32>,
+        pub spendable: bool,
+        pub txid: crate::TxId,
+    }
+    pub type ZListunspentResponse = Vec<ZListunspent>;
+}
+pub mod z_mergetoaddress {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ZMergetoaddressResponse {
+        #[serde(rename = "mergingNotes")]
+        pub merging_notes: u32,
+        #[serde(rename = "mergingShieldedValue")]
+        pub merging_shielded_value: crate::ZecAmount,
+        #[serde(rename = "mergingTransparentValue")]
+        pub merging_transparent_value: crate::ZecAmount,
+        #[serde(rename = "mergingUTXOs")]
+        pub merging_u_t_x_os: u32,
+        pub opid: String,
+        #[serde(rename = "remainingNotes")]
+        pub remaining_notes: u32,
+        #[serde(rename = "remainingShieldedValue")]
+        pub remaining_shielded_value: crate::ZecAmount,
+        #[serde(rename = "remainingTransparentValue")]
+        pub remaining_transparent_value: crate::ZecAmount,
+        #[serde(rename = "remainingUTXOs")]
+        pub remaining_u_t_x_os: u32,
+    }
+}
+pub mod z_sendmany {
+    pub type ZSendmanyResponse = String;
+}
+pub mod z_setmigration {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ZSetmigrationResponse;
+}
+pub mod z_shieldcoinbase {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ZShieldcoinbaseResponse {
+        pub opid: String,
+        #[serde(rename = "remainingUTXOs")]
+        pub remaining_u_t_x_os: u32,
+        #[serde(rename = "remainingValue")]
+        pub remaining_value: crate::ZecAmount,
+        #[serde(rename = "shieldingUTXOs")]
+        pub shielding_u_t_x_os: u32,
+        #[serde(rename = "shieldingValue")]
+        pub shielding_value: crate::ZecAmount,
+    }
+}
+pub mod z_validateaddress {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ZValidateaddressResponse {
+        pub address: crate::Address,
+        pub diversifiedtransmissionkey: String,
+        pub diversifier: String,
+        pub ismine: bool,
+        pub isvalid: bool,
+        pub payingkey: String,
+        pub transmissionkey: String,
+        #[serde(rename = "type")]
+        pub type_field: String,
+    }
+}
+pub mod z_validatepaymentdisclosure {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ZValidatepaymentdisclosureResponse;
+}
+pub mod z_viewtransaction {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Outputs {
+        pub address: crate::Address,
+        pub js: Option<u32>,
+        #[serde(rename = "jsOutput")]
+        pub js_output: Option<u32>,
+        pub memo: crate::Memo,
+        #[serde(rename = "memoStr")]
+        pub memo_str: Option<String>,
+        pub outgoing: bool,
+        pub output: Option<u32>,
+        #[serde(rename = "type")]
+        pub type_field: String,
+        pub value: crate::ZecAmount,
+        #[serde(rename = "valueZat")]
+        pub value_zat: crate::Zatoshis,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Spends {
+        pub address: crate::Address,
+        pub js: Option<u32>,
+        #[serde(rename = "jsOutputPrev")]
+        pub js_output_prev: Option<u32>,
+        #[serde(rename = "jsPrev")]
+        pub js_prev: Option<u32>,
+        #[serde(rename = "jsSpend")]
+        pub js_spend: Option<u32>,
+        #[serde(rename = "outputPrev")]
+        pub output_prev: Option<u32>,
+        pub spend: Option<u32>,
+        #[serde(rename = "txidPrev")]
+        pub txid_prev: crate::TxId,
+        #[serde(rename = "type")]
+        pub type_field: String,
+        pub value: crate::ZecAmount,
+        #[serde(rename = "valueZat")]
+        pub value_zat: crate::Zatoshis,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ZViewtransactionResponse {
+        pub outputs: Vec<Outputs>,
+        pub spends: Vec<Spends>,
+        pub txid: crate::TxId,
+    }
+}
+pub mod zcbenchmark {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Zcbenchmark {
+        pub runningtime: rust_decimal::Decimal,
+    }
+    pub type ZcbenchmarkResponse = Vec<Zcbenchmark>;
+}
+pub mod zcrawjoinsplit {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ZcrawjoinsplitResponse {
+        pub encryptednote1: String,
+        pub encryptednote2: String,
+        pub rawtxn: String,
+    }
+}
+pub mod zcrawkeygen {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ZcrawkeygenResponse {
+        pub zcaddress: crate::Address,
+        pub zcsecretkey: String,
+        pub zcviewingkey: String,
+    }
+}
+pub mod zcrawreceive {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ZcrawreceiveResponse {
+        pub amount: crate::ZecAmount,
+        pub exists: bool,
+        pub note: String,
+    }
+}
+pub mod zcsamplejoinsplit {
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct ZcsamplejoinsplitResponse;
+}
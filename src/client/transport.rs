@@ -0,0 +1,278 @@
+//! Includes `Transport`, which carries a `Client`'s requests to `zcashd`, along with `HttpTransport`, and `RecordingTransport` and `ReplayTransport`, which capture a session as a cassette and play it back without a node.
+use crate::error::TransportError;
+use crate::ResponseResult;
+use serde_json::Value;
+use std::future::Future;
+use std::io::{BufRead as _, Write as _};
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+/// The future a `Transport` resolves a request with.
+pub type TransportFuture =
+    Pin<Box<dyn Future<Output = ResponseResult<Value>> + Send>>;
+
+/// A `Transport` sends a JSON-RPC request, either a single `RequestEnvelope` or a batch of them, and resolves to the JSON of the response. JSON-RPC error responses are responses like any other, while failures to get any response are errors.
+pub trait Transport: Send + Sync {
+    fn send(&self, request: &Value) -> TransportFuture;
+}
+
+/// A shared `Transport`, e.g. a `ReplayTransport` which is checked for `remaining` recordings after the `Client` using it is done.
+impl<T: Transport + ?Sized> Transport for Arc<T> {
+    fn send(&self, request: &Value) -> TransportFuture {
+        (**self).send(request)
+    }
+}
+
+/// An `HttpTransport` POSTs requests to `zcashd`'s RPC server. It is what a `Client` uses unless it is given another `Transport`, and is built with `ClientBuilder::build_transport`.
+pub struct HttpTransport {
+    pub(crate) url: String,
+    pub(crate) auth: Arc<Mutex<crate::auth::Credentials>>,
+    pub(crate) headers: reqwest::header::HeaderMap,
+    pub(crate) timeout: Option<std::time::Duration>,
+    pub(crate) reqcli: reqwest::Client,
+}
+
+impl HttpTransport {
+    pub(crate) fn new(url: String, auth: crate::Auth) -> HttpTransport {
+        HttpTransport {
+            url,
+            auth: Arc::new(Mutex::new(crate::auth::Credentials::new(auth))),
+            headers: reqwest::header::HeaderMap::new(),
+            timeout: None,
+            reqcli: reqwest::Client::new(),
+        }
+    }
+
+    /// A POST of `request`, without its `Authorization` header.
+    fn post(&self, request: &Value) -> reqwest::RequestBuilder {
+        let mut post = self
+            .reqcli
+            .post(&self.url)
+            .headers(self.headers.clone())
+            .body(serde_json::to_string_pretty(request).unwrap());
+        if let Some(timeout) = self.timeout {
+            post = post.timeout(timeout);
+        }
+        post
+    }
+}
+
+fn authorize(
+    post: reqwest::RequestBuilder,
+    header: Option<String>,
+) -> reqwest::RequestBuilder {
+    match header {
+        Some(header) => post.header(reqwest::header::AUTHORIZATION, header),
+        None => post,
+    }
+}
+
+/// Reads the `Authorization` header afresh for each request, so a rotated cookie is picked up. A request the node refuses with HTTP 401 is sent once more if the cookie file then reads differently, since `zcashd` may have restarted with a new cookie too soon after the last for its modification time to change.
+impl Transport for HttpTransport {
+    fn send(&self, request: &Value) -> TransportFuture {
+        let post = self.post(request);
+        let auth = self.auth.clone();
+        Box::pin(async move {
+            let header = auth.lock().unwrap().header()?;
+            let resend = post.try_clone();
+            let mut response = authorize(post, header.clone()).send().await?;
+            if response.status() == reqwest::StatusCode::UNAUTHORIZED {
+                let reread = auth.lock().unwrap().reread()?;
+                if let Some(resend) = resend.filter(|_| reread != header) {
+                    response = authorize(resend, reread).send().await?;
+                }
+            }
+            let status = response.status();
+            let html = matches!(
+                response.headers().get(reqwest::header::CONTENT_TYPE),
+                Some(t) if t.as_bytes().starts_with(b"text/html")
+            );
+            parse_body(status, html, response.text().await?)
+        })
+    }
+}
+
+/// Parse the body of a response with `status`. `zcashd` sends JSON error responses with 404 and 500 statuses too, so those are parsed like any other, while statuses which come without a JSON body are reported as such.
+fn parse_body(
+    status: reqwest::StatusCode,
+    html: bool,
+    text: String,
+) -> ResponseResult<Value> {
+    use crate::error::JsonRpcViolation::MalformedJson;
+    use crate::Error::{
+        JsonRpcViolation, Unauthorized, UnexpectedBody, WorkQueueExceeded,
+    };
+    use reqwest::StatusCode;
+
+    match status {
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+            return Err(Unauthorized { status })
+        }
+        StatusCode::SERVICE_UNAVAILABLE => return Err(WorkQueueExceeded),
+        _ => (),
+    }
+    if html || text.trim().is_empty() {
+        return Err(UnexpectedBody { status, body: text });
+    }
+    match crate::json::parse_string(text) {
+        Err(JsonRpcViolation(MalformedJson { input_text, .. }))
+            if !status.is_success() =>
+        {
+            Err(UnexpectedBody {
+                status,
+                body: input_text,
+            })
+        }
+        parsed => parsed,
+    }
+}
+
+/// One request and its response, as a line of a cassette.
+#[derive(serde::Deserialize, serde::Serialize)]
+struct Interaction {
+    request: Value,
+    response: Value,
+}
+
+/// A `RecordingTransport` sends requests with another `Transport`, and appends each request and its response to a cassette, a file with one JSON object per line, for `ReplayTransport` to play back. Requests which fail without a response aren't recorded.
+pub struct RecordingTransport<T> {
+    inner: T,
+    cassette: Arc<Mutex<std::fs::File>>,
+}
+
+impl<T: Transport> RecordingTransport<T> {
+    /// Record the requests sent with `inner` to a new cassette at `path`, replacing any file there.
+    pub fn create(
+        inner: T,
+        path: impl AsRef<Path>,
+    ) -> std::io::Result<RecordingTransport<T>> {
+        Ok(RecordingTransport {
+            inner,
+            cassette: Arc::new(Mutex::new(std::fs::File::create(path)?)),
+        })
+    }
+}
+
+impl<T: Transport> Transport for RecordingTransport<T> {
+    fn send(&self, request: &Value) -> TransportFuture {
+        let response = self.inner.send(request);
+        let request = request.clone();
+        let cassette = self.cassette.clone();
+        Box::pin(async move {
+            let response = response.await?;
+            let mut line = serde_json::to_string(&Interaction {
+                request,
+                response: response.clone(),
+            })
+            .unwrap();
+            line.push('\n');
+            cassette
+                .lock()
+                .unwrap()
+                .write_all(line.as_bytes())
+                .map_err(TransportError::Cassette)?;
+            Ok(response)
+        })
+    }
+}
+
+/// A `ReplayTransport` answers requests with the responses in a cassette written by `RecordingTransport`, without sending them anywhere. A request is answered by the first unused recording of the same calls, with the same methods and params, whatever their ids, so a replayed session only needs to make the same calls as the recorded one. The ids of the recorded response are replaced by those of the request.
+pub struct ReplayTransport {
+    interactions: Mutex<Vec<Option<Interaction>>>,
+}
+
+impl ReplayTransport {
+    /// Load the cassette at `path`.
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<ReplayTransport> {
+        let reader = std::io::BufReader::new(std::fs::File::open(path)?);
+        let mut interactions = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let interaction = serde_json::from_str(&line).map_err(|e| {
+                std::io::Error::new(std::io::ErrorKind::InvalidData, e)
+            })?;
+            interactions.push(Some(interaction));
+        }
+        Ok(ReplayTransport {
+            interactions: Mutex::new(interactions),
+        })
+    }
+
+    /// The number of recorded responses which haven't been replayed yet.
+    pub fn remaining(&self) -> usize {
+        let interactions = self.interactions.lock().unwrap();
+        interactions.iter().filter(|i| i.is_some()).count()
+    }
+
+    fn replay(&self, request: &Value) -> ResponseResult<Value> {
+        let mut interactions = self.interactions.lock().unwrap();
+        let unused = interactions.iter_mut().find(|interaction| {
+            matches!(interaction, Some(recorded) if same_calls(&recorded.request, request))
+        });
+        match unused.and_then(Option::take) {
+            Some(Interaction {
+                request: recorded,
+                mut response,
+            }) => {
+                replace_ids(&recorded, request, &mut response);
+                Ok(response)
+            }
+            None => Err(TransportError::NotRecorded {
+                request: request.clone(),
+            }
+            .into()),
+        }
+    }
+}
+
+impl Transport for ReplayTransport {
+    fn send(&self, request: &Value) -> TransportFuture {
+        let replayed = self.replay(request);
+        Box::pin(async move { replayed })
+    }
+}
+
+/// Whether two requests make the same calls, ignoring their ids.
+fn same_calls(a: &Value, b: &Value) -> bool {
+    fn call(envelope: &Value) -> (Option<&Value>, Option<&Value>) {
+        (envelope.get("method"), envelope.get("params"))
+    }
+    match (a, b) {
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len()
+                && a.iter().zip(b).all(|(a, b)| call(a) == call(b))
+        }
+        (Value::Array(_), _) | (_, Value::Array(_)) => false,
+        (a, b) => call(a) == call(b),
+    }
+}
+
+/// Give the responses to `recorded` the ids of the corresponding calls in `request`.
+fn replace_ids(recorded: &Value, request: &Value, response: &mut Value) {
+    let ids = |request: &Value| -> Vec<Value> {
+        match request {
+            Value::Array(calls) => {
+                calls.iter().map(|c| c["id"].clone()).collect()
+            }
+            call => vec![call["id"].clone()],
+        }
+    };
+    let renumbered: Vec<(Value, Value)> =
+        ids(recorded).into_iter().zip(ids(request)).collect();
+    let responses = match response {
+        Value::Array(responses) => responses.iter_mut().collect(),
+        response => vec![response],
+    };
+    for response in responses {
+        if let Some(id) = response.get_mut("id") {
+            if let Some((_, new)) = renumbered.iter().find(|(old, _)| old == id)
+            {
+                *id = new.clone();
+            }
+        }
+    }
+}
@@ -1,5 +1,10 @@
+use std::sync::Arc;
+
+/// The path of the cookie file of a `zcashd` following `network` with the default data directory, `~/.zcash`.
 #[cfg(feature = "cookie-finder")]
-pub fn get_cookie(regtest: bool) -> std::io::Result<String> {
+pub fn get_cookie_path(
+    network: crate::Network,
+) -> std::io::Result<std::path::PathBuf> {
     let mut cookie_path = match dirs::home_dir() {
         Some(x) => x,
         None => {
@@ -16,26 +21,81 @@ pub fn get_cookie(regtest: bool) -> std::io::Result<String> {
     };
 
     cookie_path.push(".zcash");
-    if regtest {
-        cookie_path.push("regtest");
+    if let Some(subdirectory) = network.datadir_subdirectory() {
+        cookie_path.push(subdirectory);
     }
     cookie_path.push(".cookie");
+    Ok(cookie_path)
+}
 
-    let mut cookie_file = std::fs::File::open(cookie_path)?;
+#[cfg(feature = "cookie-finder")]
+pub fn get_cookie(network: crate::Network) -> std::io::Result<String> {
+    let mut cookie_file = std::fs::File::open(get_cookie_path(network)?)?;
     let mut cookie_string = String::new();
     use std::io::Read as _;
     cookie_file.read_to_string(&mut cookie_string)?;
     Ok(cookie_string)
 }
 
-pub fn get_zcashd_port() -> String {
-    //This could theoretically be expanded to do some sort of
-    //automatic port lookup
-    String::from("127.0.0.1:18232")
+/// Make a `Client` for the node configured by the default `~/.zcash/zcash.conf`.
+#[cfg(feature = "cookie-finder")]
+pub fn make_client() -> crate::Client {
+    crate::ZcashConf::load(None, None)
+        .unwrap()
+        .client()
+        .unwrap()
 }
 
-pub fn make_client(regtest: bool) -> crate::Client {
-    crate::Client::new(get_zcashd_port(), get_cookie(regtest).unwrap())
+/// Serialize the positional `params` of a call to `method`. Optional params which are `None` are omitted from the end of the array, and an optional param given after an omitted one is an error, since positional params can't skip a slot.
+pub(crate) fn build_params(
+    method: &'static str,
+    required: Vec<(&'static str, serde_json::Result<serde_json::Value>)>,
+    optional: Vec<(
+        &'static str,
+        Option<serde_json::Result<serde_json::Value>>,
+    )>,
+) -> crate::ResponseResult<Vec<serde_json::Value>> {
+    use crate::error::InvalidRequest::{OptionalParamGap, Unserializable};
+
+    let mut params = Vec::with_capacity(required.len() + optional.len());
+    for (param, value) in required {
+        params.push(value.map_err(|reason| Unserializable {
+            method,
+            param,
+            reason,
+        })?);
+    }
+    let mut omitted = None;
+    for (param, value) in optional {
+        match (value, omitted) {
+            (None, None) => omitted = Some(param),
+            (None, Some(_)) => (),
+            (Some(_), Some(omitted)) => {
+                return Err(OptionalParamGap {
+                    method,
+                    omitted,
+                    given: param,
+                }
+                .into())
+            }
+            (Some(value), None) => {
+                params.push(value.map_err(|reason| Unserializable {
+                    method,
+                    param,
+                    reason,
+                })?)
+            }
+        }
+    }
+    Ok(params)
+}
+
+/// 64 bits which are random enough to spread out retries or make a test password, taken from the randomly keyed hasher `std` uses for `HashMap`, to avoid a dependency on `rand`.
+pub(crate) fn random_u64() -> u64 {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher as _, Hasher as _};
+
+    RandomState::new().build_hasher().finish()
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -68,37 +128,100 @@ impl RequestEnvelope {
 }
 
 pub(crate) struct InnerCli {
-    pub(crate) url: String,
-    pub(crate) auth: String,
-    pub(crate) reqcli: reqwest::Client,
-    pub(crate) idit: std::ops::RangeFrom<u64>,
+    pub(crate) transport: Arc<dyn super::transport::Transport>,
+    pub(crate) ids: Arc<std::sync::atomic::AtomicU64>,
+    pub(crate) network: Option<crate::Network>,
+    pub(crate) mismatch: Option<(crate::Network, crate::Network)>,
+    pub(crate) retry: Option<Arc<super::retry::RetryPolicy>>,
 }
 
 impl InnerCli {
-    pub(crate) fn new(hostport: String, authcookie: String) -> Self {
+    pub(crate) fn new(hostport: String, auth: crate::Auth) -> Self {
+        Self::with_transport(Arc::new(super::transport::HttpTransport::new(
+            format!("http://{}/", hostport),
+            auth,
+        )))
+    }
+    pub(crate) fn with_transport(
+        transport: Arc<dyn super::transport::Transport>,
+    ) -> Self {
         Self {
-            url: format!("http://{}/", hostport),
-            auth: format!("Basic {}", base64::encode(authcookie)),
-            reqcli: reqwest::Client::new(),
-            idit: (0..),
+            transport,
+            ids: Arc::new(std::sync::atomic::AtomicU64::new(0)),
+            network: None,
+            mismatch: None,
+            retry: None,
         }
     }
+    /// A handle on the same node and settings, which shares the sequence of request ids, so its requests can be told apart from this one's.
+    pub(crate) fn share(&self) -> Self {
+        Self {
+            transport: self.transport.clone(),
+            ids: self.ids.clone(),
+            network: self.network,
+            mismatch: self.mismatch,
+            retry: self.retry.clone(),
+        }
+    }
+    fn next_id(&self) -> u64 {
+        self.ids.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+    }
     pub(crate) fn procedure_call(
         &mut self,
         method: &'static str,
         args: Vec<serde_json::Value>,
-    ) -> (
-        u64,
-        impl std::future::Future<Output = Result<reqwest::Response, reqwest::Error>>,
-    ) {
-        let id = self.idit.next().unwrap();
-        (
-            id,
-            self.reqcli
-                .post(&self.url)
-                .header("Authorization", &self.auth)
-                .body(&RequestEnvelope::wrap(id, method, args))
-                .send(),
-        )
+    ) -> crate::ResponseResult<(u64, Request)> {
+        let id = self.next_id();
+        let body =
+            serde_json::to_value(RequestEnvelope::wrap(id, method, args))
+                .unwrap();
+        Ok((id, self.request(&[method], body)?))
+    }
+    pub(crate) fn batch_call(
+        &mut self,
+        calls: Vec<(&'static str, Vec<serde_json::Value>)>,
+    ) -> crate::ResponseResult<(Vec<u64>, Request)> {
+        let methods: Vec<&'static str> =
+            calls.iter().map(|(method, _)| *method).collect();
+        let envelopes: Vec<RequestEnvelope> = calls
+            .into_iter()
+            .map(|(method, args)| {
+                RequestEnvelope::wrap(self.next_id(), method, args)
+            })
+            .collect();
+        let ids = envelopes.iter().map(|envelope| envelope.id).collect();
+        let body = serde_json::to_value(envelopes).unwrap();
+        Ok((ids, self.request(&methods, body)?))
+    }
+    fn request(
+        &self,
+        methods: &[&'static str],
+        body: serde_json::Value,
+    ) -> crate::ResponseResult<Request> {
+        if let Some((configured, node)) = self.mismatch {
+            return Err(crate::Error::NetworkMismatch { configured, node });
+        }
+        let retry = self.retry.clone().filter(|policy| {
+            methods.iter().all(|method| policy.allows(method))
+        });
+        Ok(Request {
+            transport: self.transport.clone(),
+            body,
+            retry,
+        })
+    }
+}
+
+/// A request, along with the `Transport` to send it with, as many times as its `RetryPolicy` allows.
+pub(crate) struct Request {
+    transport: Arc<dyn super::transport::Transport>,
+    body: serde_json::Value,
+    pub(crate) retry: Option<Arc<super::retry::RetryPolicy>>,
+}
+
+impl Request {
+    /// Send the request, resolving to the JSON of the response. Each call sends the request again.
+    pub(crate) fn send(&self) -> super::transport::TransportFuture {
+        self.transport.send(&self.body)
     }
 }
@@ -0,0 +1,237 @@
+//! The `config` mod includes `ZcashConf`, which reads the connection settings of a `zcashd` node from its `zcash.conf`, the way `zcashd` and `zcash-cli` do.
+
+use crate::client::builder::ClientBuilder;
+use crate::error::ConfError;
+use crate::{Auth, Client, Network};
+use std::path::{Path, PathBuf};
+
+/// A `ZcashConf` holds the settings from a `zcash.conf` which determine how to reach and authenticate to the node's RPC server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ZcashConf {
+    /// The data directory the configuration was read from, as given by `-datadir`.
+    pub datadir: PathBuf,
+    /// `Network::Main` unless `testnet=1` or `regtest=1` is set.
+    pub network: Network,
+    pub rpcport: Option<u16>,
+    /// The first `rpcbind`, an address the node listens on, optionally with a port.
+    pub rpcbind: Option<String>,
+    pub rpcuser: Option<String>,
+    pub rpcpassword: Option<String>,
+}
+
+impl ZcashConf {
+    /// Read the configuration `zcashd -datadir=<datadir> -conf=<conf>` would, where `datadir` defaults to `~/.zcash` and `conf` to `zcash.conf` in `datadir`. A missing default `zcash.conf` reads as an empty configuration, as it does for `zcashd`.
+    pub fn load(
+        datadir: Option<&Path>,
+        conf: Option<&Path>,
+    ) -> Result<ZcashConf, ConfError> {
+        let datadir = match datadir {
+            Some(datadir) => datadir.to_path_buf(),
+            None => default_datadir()?,
+        };
+        let contents = match conf {
+            Some(conf) => read(&datadir.join(conf))?,
+            None => match read(&datadir.join("zcash.conf")) {
+                Err(ConfError::Read { reason, .. })
+                    if reason.kind() == std::io::ErrorKind::NotFound =>
+                {
+                    String::new()
+                }
+                contents => contents?,
+            },
+        };
+        ZcashConf::parse(&contents, datadir)
+    }
+
+    /// Parse the `contents` of a `zcash.conf` in `datadir`, reading the files it names with `includeconf` from `datadir`, unless they are absolute.
+    pub fn parse(
+        contents: &str,
+        datadir: PathBuf,
+    ) -> Result<ZcashConf, ConfError> {
+        let mut settings = Vec::new();
+        parse_settings(contents, &mut settings)?;
+        let includes: Vec<PathBuf> = settings
+            .iter()
+            .filter(|(key, _)| key == "includeconf")
+            .map(|(_, path)| datadir.join(path))
+            .collect();
+        // Included files can't include further files, and settings from the
+        // main file take precedence, because the first value of a key wins.
+        for include in includes {
+            let mut included = Vec::new();
+            parse_settings(&read(&include)?, &mut included)?;
+            settings.extend(
+                included.into_iter().filter(|(key, _)| key != "includeconf"),
+            );
+        }
+        let first = |key: &str| {
+            settings
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, value)| value.clone())
+        };
+        let flag = |key: &str| match first(key) {
+            Some(value) => is_set(&value),
+            None => false,
+        };
+        let network = match (flag("testnet"), flag("regtest")) {
+            (false, false) => Network::Main,
+            (true, false) => Network::Test,
+            (false, true) => Network::Regtest,
+            (true, true) => return Err(ConfError::ConflictingNetworks),
+        };
+        let rpcport = match first("rpcport") {
+            Some(port) => {
+                Some(port.parse().map_err(|_| ConfError::InvalidValue {
+                    key: "rpcport",
+                    value: port,
+                })?)
+            }
+            None => None,
+        };
+        Ok(ZcashConf {
+            datadir,
+            network,
+            rpcport,
+            rpcbind: first("rpcbind"),
+            rpcuser: first("rpcuser"),
+            rpcpassword: first("rpcpassword"),
+        })
+    }
+
+    /// The directory the node keeps its chain state and `.cookie` in, which is a subdirectory of `datadir` for testnet and regtest.
+    pub fn network_datadir(&self) -> PathBuf {
+        match self.network.datadir_subdirectory() {
+            Some(subdirectory) => self.datadir.join(subdirectory),
+            None => self.datadir.clone(),
+        }
+    }
+
+    /// The URL of the RPC server. The host is the first `rpcbind`, or localhost if that is unset or a wildcard address, and the port is the one in `rpcbind`, `rpcport` or the network's default, in that order.
+    pub fn url(&self) -> Result<String, ConfError> {
+        let (host, bound_port) = match &self.rpcbind {
+            Some(rpcbind) => split_host_port(rpcbind)?,
+            None => (String::from("127.0.0.1"), None),
+        };
+        let host = match host.as_str() {
+            "" | "0.0.0.0" => String::from("127.0.0.1"),
+            "::" => String::from("::1"),
+            _ => host,
+        };
+        let host = if host.contains(':') {
+            format!("[{}]", host)
+        } else {
+            host
+        };
+        let port = bound_port
+            .or(self.rpcport)
+            .unwrap_or_else(|| self.network.default_rpc_port());
+        Ok(format!("http://{}:{}/", host, port))
+    }
+
+    /// `rpcuser` and `rpcpassword` if a password is set, and otherwise the cookie file in `network_datadir`.
+    pub fn auth(&self) -> Auth {
+        match &self.rpcpassword {
+            Some(password) => Auth::UserPass {
+                user: self.rpcuser.clone().unwrap_or_default(),
+                password: password.clone(),
+            },
+            None => Auth::CookieFile(self.network_datadir().join(".cookie")),
+        }
+    }
+
+    /// A `ClientBuilder` for the node, with its URL, `Auth` and `Network` set.
+    pub fn client_builder(&self) -> Result<ClientBuilder, ConfError> {
+        Ok(Client::builder(self.url()?)
+            .auth(self.auth())
+            .network(self.network))
+    }
+
+    /// A `Client` for the node, with its URL, `Auth` and `Network` set.
+    pub fn client(&self) -> Result<Client, ConfError> {
+        Ok(self.client_builder()?.build()?)
+    }
+}
+
+#[cfg(feature = "cookie-finder")]
+fn default_datadir() -> Result<PathBuf, ConfError> {
+    dirs::home_dir()
+        .map(|home| home.join(".zcash"))
+        .ok_or(ConfError::NoDatadir)
+}
+
+#[cfg(not(feature = "cookie-finder"))]
+fn default_datadir() -> Result<PathBuf, ConfError> {
+    Err(ConfError::NoDatadir)
+}
+
+fn read(path: &Path) -> Result<String, ConfError> {
+    std::fs::read_to_string(path).map_err(|reason| ConfError::Read {
+        path: path.to_path_buf(),
+        reason,
+    })
+}
+
+/// Append the `key=value` settings of `contents` to `settings`, in order.
+fn parse_settings(
+    contents: &str,
+    settings: &mut Vec<(String, String)>,
+) -> Result<(), ConfError> {
+    for (index, line) in contents.lines().enumerate() {
+        let line = match line.find('#') {
+            Some(comment) => &line[..comment],
+            None => line,
+        }
+        .trim();
+        if line.is_empty() {
+            continue;
+        }
+        match line.find('=') {
+            Some(equals) => settings.push((
+                line[..equals].trim().trim_start_matches('-').to_string(),
+                line[equals + 1..].trim().to_string(),
+            )),
+            None => return Err(ConfError::Malformed { line: index + 1 }),
+        }
+    }
+    Ok(())
+}
+
+/// Interpret a boolean setting as `zcashd`'s `InterpretBool` does: an empty value sets it, and otherwise the leading integer `atoi` parses must be nonzero, so `1` and `2yes` set it, but `0`, `true` and `yes` don't.
+fn is_set(value: &str) -> bool {
+    if value.is_empty() {
+        return true;
+    }
+    let number = value.trim_start();
+    let digits = number.strip_prefix(&['+', '-'][..]).unwrap_or(number);
+    digits
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .any(|digit| digit != '0')
+}
+
+/// Split an `rpcbind` like `127.0.0.1`, `host:8232`, `::1` or `[::1]:8232` into its host and port.
+fn split_host_port(rpcbind: &str) -> Result<(String, Option<u16>), ConfError> {
+    let invalid = || ConfError::InvalidValue {
+        key: "rpcbind",
+        value: rpcbind.to_string(),
+    };
+    let parse_port = |port: &str| port.parse().map_err(|_| invalid());
+    if let Some(bracketed) = rpcbind.strip_prefix('[') {
+        let close = bracketed.find(']').ok_or_else(invalid)?;
+        let host = bracketed[..close].to_string();
+        match &bracketed[close + 1..] {
+            "" => Ok((host, None)),
+            rest => {
+                let port = rest.strip_prefix(':').ok_or_else(invalid)?;
+                Ok((host, Some(parse_port(port)?)))
+            }
+        }
+    } else if rpcbind.matches(':').count() == 1 {
+        let colon = rpcbind.find(':').unwrap();
+        let port = parse_port(&rpcbind[colon + 1..])?;
+        Ok((rpcbind[..colon].to_string(), Some(port)))
+    } else {
+        Ok((rpcbind.to_string(), None))
+    }
+}
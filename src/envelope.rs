@@ -5,11 +5,22 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ResponseEnvelope {
-    id: u64,
+    pub(crate) id: u64,
+    /// `None` only when the envelope has no `result` at all. A `null` result is `Some(Value::Null)`, since `zcashd` sends it on success for methods with nothing to respond with, e.g. `ping`.
+    #[serde(default, deserialize_with = "present")]
     result: Option<serde_json::Value>,
     error: Option<ResponseError>,
 }
 
+fn present<'de, D>(
+    deserializer: D,
+) -> Result<Option<serde_json::Value>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    serde_json::Value::deserialize(deserializer).map(Some)
+}
+
 impl ResponseEnvelope {
     pub fn unwrap<R>(self, clientid: u64) -> ResponseResult<R>
     where
@@ -38,6 +49,7 @@ impl ResponseEnvelope {
         } else {
             match (self.result, self.error) {
                 (None, None) => Err(JsonRpcViolation(NoResultOrError)),
+                (Some(serde_json::Value::Null), Some(e)) => Err(Response(e)),
                 (Some(r), Some(e)) => Err(JsonRpcViolation(ResultAndError {
                     result: r,
                     error: e,
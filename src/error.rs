@@ -1,4 +1,4 @@
-//! The `error` mod includes types representing specific errors which are all bundled into the top-level `Error` enum.
+//! The `error` mod includes types representing specific errors which are all bundled into the top-level `Error` enum. An error which wraps another describes only itself in `Display`, and returns the wrapped error as its `source`, so that reporters like `anyhow` print each cause once. Variants which only forward another error, like `Error::Response`, display and return the source of that error instead.
 
 use serde::{Deserialize, Serialize};
 
@@ -19,15 +19,354 @@ pub enum Error {
 
     /// The `Http` variant indicates some HTTP-layer error and passes errors directly from the `reqwest` HTTP client dependency.
     Http(reqwest::Error),
+
+    /// An `InvalidRequest` is detected client-side, so the request is never sent to `zcashd`.
+    InvalidRequest(InvalidRequest),
+
+    /// An `Auth` error occurs when the cookie file of an `Auth::CookieFile` can't be read, so the request is never sent to `zcashd`.
+    Auth(std::io::Error),
+
+    /// A `NetworkMismatch` occurs when `Client::verify_network` finds the node follows a different chain than the `Client` was configured for. The `Client` then refuses to send any further requests.
+    #[from(ignore)]
+    NetworkMismatch {
+        configured: crate::Network,
+        node: crate::Network,
+    },
+
+    /// An `Unauthorized` error occurs when the server responds with HTTP 401, because the credentials are wrong, or 403, because `rpcallowip` doesn't allow the client's address.
+    #[from(ignore)]
+    Unauthorized { status: reqwest::StatusCode },
+
+    /// A `WorkQueueExceeded` error occurs when `zcashd` responds with HTTP 503 because it has more requests queued than `rpcworkqueue` allows. The request wasn't handled, so it can be sent again later.
+    WorkQueueExceeded,
+
+    /// An `UnexpectedBody` occurs when the server responds with an empty or HTML body, or with a body which isn't JSON along with an unsuccessful status, e.g. the error page of a proxy in front of `zcashd`.
+    #[from(ignore)]
+    UnexpectedBody {
+        status: reqwest::StatusCode,
+        body: String,
+    },
+
+    /// A `Transport` error comes from a `Transport` other than HTTP, e.g. a `ReplayTransport` without a recording of the request.
+    Transport(TransportError),
+
+    /// A `Call` error wraps any other error from a `Client` call with the `method` which failed and the `id` of its request, which is `None` when the call failed before a request was made.
+    #[from(ignore)]
+    Call {
+        method: &'static str,
+        id: Option<u64>,
+        error: Box<Error>,
+    },
+
+    /// An `Operation` error occurs when an asynchronous operation started by e.g. `z_sendmany` doesn't send a transaction, or can't be waited for.
+    #[from(ignore)]
+    Operation {
+        opid: crate::operation::OperationId,
+        failure: OperationFailure,
+    },
+
+    /// A `ReorgTooDeep` error occurs when a `BlockStream` finds the node's chain no longer includes any of the `depth` blocks it remembers, so it can't tell where the reorg forked from.
+    #[from(ignore)]
+    ReorgTooDeep { depth: usize },
+}
+
+impl Error {
+    /// Wrap the error with the `method` and request `id` of the call it occurred in.
+    pub(crate) fn in_call(
+        self,
+        method: &'static str,
+        id: Option<u64>,
+    ) -> Error {
+        Error::Call {
+            method,
+            id,
+            error: Box::new(self),
+        }
+    }
+
+    /// The error itself, or for a `Call`, the error it wraps.
+    pub fn inner(&self) -> &Error {
+        match self {
+            Error::Call { error, .. } => error.inner(),
+            error => error,
+        }
+    }
+
+    /// The error itself, or for a `Call`, the error it wraps, e.g. to match on its variant.
+    pub fn into_inner(self) -> Error {
+        match self {
+            Error::Call { error, .. } => error.into_inner(),
+            error => error,
+        }
+    }
+
+    /// The method of the call which failed, if known.
+    pub fn method(&self) -> Option<&'static str> {
+        match self {
+            Error::Call { method, .. } => Some(method),
+            Error::InvalidRequest(e) => Some(e.method()),
+            _ => None,
+        }
+    }
+
+    /// The id of the request which failed, if one was made.
+    pub fn request_id(&self) -> Option<u64> {
+        match self {
+            Error::Call { id, .. } => *id,
+            _ => None,
+        }
+    }
+
+    /// The `RpcErrorCode` of an application-level error from `zcashd`, or `None` for any other kind of error.
+    pub fn rpc_code(&self) -> Option<RpcErrorCode> {
+        match self.inner() {
+            Error::Response(e) => Some(e.code),
+            Error::Operation {
+                failure: OperationFailure::Failed(e),
+                ..
+            } => Some(e.code),
+            _ => None,
+        }
+    }
+
+    /// Whether `zcashd` rejected the request because the wallet can't fund it.
+    pub fn is_insufficient_funds(&self) -> bool {
+        self.rpc_code() == Some(RpcErrorCode::WalletInsufficientFunds)
+    }
+
+    /// Whether `zcashd` rejected an address, key, txid or block hash param as invalid or unknown.
+    pub fn is_invalid_address_or_key(&self) -> bool {
+        self.rpc_code() == Some(RpcErrorCode::InvalidAddressOrKey)
+    }
+
+    /// Whether `zcashd` is still starting up, and will answer the request once it has loaded the chain.
+    pub fn is_in_warmup(&self) -> bool {
+        self.rpc_code() == Some(RpcErrorCode::InWarmup)
+    }
+
+    /// Whether `zcashd` rejected a transaction or block as invalid.
+    pub fn is_verify_rejected(&self) -> bool {
+        self.rpc_code() == Some(RpcErrorCode::VerifyRejected)
+    }
+
+    /// Whether a transaction sent with `sendrawtransaction` is already in the chain.
+    pub fn is_already_in_chain(&self) -> bool {
+        self.rpc_code() == Some(RpcErrorCode::VerifyAlreadyInChain)
+    }
+
+    /// Whether the request needs the wallet to be unlocked with `walletpassphrase` first.
+    pub fn is_wallet_locked(&self) -> bool {
+        self.rpc_code() == Some(RpcErrorCode::WalletUnlockNeeded)
+    }
+
+    /// Whether `zcashd` doesn't implement the method, e.g. because it was built without the wallet or the method is deprecated.
+    pub fn is_method_not_found(&self) -> bool {
+        self.rpc_code() == Some(RpcErrorCode::MethodNotFound)
+    }
 }
 
 /// The `ResponseError` represents any application-level error sent from `zcashd`.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ResponseError {
-    pub code: i64,
+    pub code: RpcErrorCode,
     pub message: String,
 }
 
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Response(e) => write!(f, "{}", e),
+            Error::UnexpectedResponse(e) => write!(f, "{}", e),
+            Error::JsonRpcViolation(e) => write!(f, "{}", e),
+            Error::Http(_) => f.write_str("http request failed"),
+            Error::InvalidRequest(e) => write!(f, "{}", e),
+            Error::Auth(_) => f.write_str("can't read the cookie file"),
+            Error::NetworkMismatch { configured, node } => write!(
+                f,
+                "the client is configured for {}, but the node follows {}",
+                configured, node
+            ),
+            Error::Unauthorized { status } => {
+                write!(f, "the node refused the credentials: {}", status)
+            }
+            Error::WorkQueueExceeded => {
+                f.write_str("the node's RPC work queue is full")
+            }
+            Error::UnexpectedBody { status, body } => {
+                if body.trim().is_empty() {
+                    write!(f, "empty response body with status {}", status)
+                } else {
+                    write!(f, "non-JSON response body with status {}", status)
+                }
+            }
+            Error::Transport(e) => write!(f, "{}", e),
+            Error::Call {
+                method,
+                id: Some(id),
+                ..
+            } => write!(f, "{} request {} failed", method, id),
+            Error::Call { method, .. } => write!(f, "{} failed", method),
+            Error::Operation { opid, failure } => {
+                write!(f, "operation {} {}", opid, failure)
+            }
+            Error::ReorgTooDeep { depth } => write!(
+                f,
+                "a reorg disconnected all {} blocks the stream remembers",
+                depth
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Response(e) => e.source(),
+            Error::UnexpectedResponse(e) => e.source(),
+            Error::JsonRpcViolation(e) => e.source(),
+            Error::Http(e) => Some(e),
+            Error::InvalidRequest(e) => e.source(),
+            Error::Auth(e) => Some(e),
+            Error::NetworkMismatch { .. }
+            | Error::Unauthorized { .. }
+            | Error::WorkQueueExceeded
+            | Error::UnexpectedBody { .. } => None,
+            Error::Transport(e) => e.source(),
+            Error::Call { error, .. } => Some(error.as_ref()),
+            Error::Operation {
+                failure: OperationFailure::Failed(e),
+                ..
+            } => Some(e),
+            Error::Operation { .. } | Error::ReorgTooDeep { .. } => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ResponseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "zcashd error {} ({:?}): {}",
+            i64::from(self.code),
+            self.code,
+            self.message
+        )
+    }
+}
+
+impl std::error::Error for ResponseError {}
+
+/// An `OperationFailure` is why an `Operation` didn't resolve to a `txid`.
+#[derive(Debug)]
+pub enum OperationFailure {
+    /// The operation failed with the error `z_getoperationresult` reported.
+    Failed(ResponseError),
+    /// The operation was cancelled before it ran.
+    Cancelled,
+    /// `zcashd` has no record of the operation, e.g. because it restarted, or the operation's result was already taken with `z_getoperationresult`.
+    Unknown,
+    /// The operation hadn't finished when the timeout passed, and was last seen in `state`.
+    TimedOut {
+        state: crate::operation::OperationState,
+    },
+    /// The operation succeeded, but its result has no `txid`.
+    MissingTxid,
+}
+
+impl std::fmt::Display for OperationFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OperationFailure::Failed(_) => f.write_str("failed"),
+            OperationFailure::Cancelled => f.write_str("cancelled"),
+            OperationFailure::Unknown => f.write_str("unknown to the node"),
+            OperationFailure::TimedOut { state } => {
+                write!(f, "timed out while {:?}", state)
+            }
+            OperationFailure::MissingTxid => {
+                f.write_str("succeeded without a txid")
+            }
+        }
+    }
+}
+
+macro_rules! rpc_error_codes {
+    ( $( $(#[$doc:meta])* $variant:ident = $code:expr, )* ) => {
+        /// An `RpcErrorCode` is the `code` of a `ResponseError`, named as in `zcashd`'s `rpc/protocol.h` without the `RPC_` prefix. Codes this crate doesn't know are kept as `Unknown`.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+        #[serde(from = "i64", into = "i64")]
+        pub enum RpcErrorCode {
+            $( $(#[$doc])* $variant, )*
+            Unknown(i64),
+        }
+
+        impl From<i64> for RpcErrorCode {
+            fn from(code: i64) -> RpcErrorCode {
+                match code {
+                    $( $code => RpcErrorCode::$variant, )*
+                    code => RpcErrorCode::Unknown(code),
+                }
+            }
+        }
+
+        impl From<RpcErrorCode> for i64 {
+            fn from(code: RpcErrorCode) -> i64 {
+                match code {
+                    $( RpcErrorCode::$variant => $code, )*
+                    RpcErrorCode::Unknown(code) => code,
+                }
+            }
+        }
+    };
+}
+
+rpc_error_codes! {
+    /// The request isn't a valid JSONRPC request.
+    InvalidRequest = -32600,
+    MethodNotFound = -32601,
+    InvalidParams = -32602,
+    InternalError = -32603,
+    ParseError = -32700,
+
+    /// An error without a more specific code.
+    MiscError = -1,
+    ForbiddenBySafeMode = -2,
+    /// A param has the wrong JSON type.
+    TypeError = -3,
+    InvalidAddressOrKey = -5,
+    OutOfMemory = -7,
+    /// A param has an invalid value.
+    InvalidParameter = -8,
+    DatabaseError = -20,
+    /// A serialized transaction or block param can't be deserialized.
+    DeserializationError = -22,
+    /// A transaction or block failed verification for a reason other than a rule violation.
+    VerifyError = -25,
+    /// A transaction or block violates a consensus or policy rule.
+    VerifyRejected = -26,
+    VerifyAlreadyInChain = -27,
+    /// The node is still loading, e.g. "Loading block index...".
+    InWarmup = -28,
+
+    ClientNotConnected = -9,
+    ClientInInitialDownload = -10,
+    ClientNodeAlreadyAdded = -23,
+    ClientNodeNotAdded = -24,
+    ClientNodeNotConnected = -29,
+    ClientInvalidIpOrSubnet = -30,
+
+    /// A wallet error without a more specific code.
+    WalletError = -4,
+    WalletInsufficientFunds = -6,
+    WalletInvalidAccountName = -11,
+    WalletKeypoolRanOut = -12,
+    WalletUnlockNeeded = -13,
+    WalletPassphraseIncorrect = -14,
+    /// A wallet command was issued in the wrong encryption state, e.g. `encryptwallet` on an encrypted wallet.
+    WalletWrongEncState = -15,
+    WalletEncryptionFailed = -16,
+    WalletAlreadyUnlocked = -17,
+}
+
 /// An `UnexpectedResponse` occurs when `zcashd` responds with valid JSON that doesn't match the expected types of this crate.
 #[derive(Debug)]
 pub struct UnexpectedResponse {
@@ -35,6 +374,18 @@ pub struct UnexpectedResponse {
     pub reason: serde_json::Error,
 }
 
+impl std::fmt::Display for UnexpectedResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("unexpected response structure")
+    }
+}
+
+impl std::error::Error for UnexpectedResponse {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.reason)
+    }
+}
+
 /// A `JsonRpcViolation` occurs when `zcashd` responds with malformed JSON or with a response envelope that violates this crate's assumed JSONRPC protocol invariants.
 #[derive(Debug)]
 pub enum JsonRpcViolation {
@@ -46,9 +397,437 @@ pub enum JsonRpcViolation {
         client: u64,
         server: u64,
     },
+    MissingBatchResponse {
+        client: u64,
+    },
     NoResultOrError,
     ResultAndError {
         result: serde_json::Value,
         error: ResponseError,
     },
 }
+
+impl std::fmt::Display for JsonRpcViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JsonRpcViolation::MalformedJson { .. } => {
+                f.write_str("malformed JSON response")
+            }
+            JsonRpcViolation::UnexpectedServerId { client, server } => write!(
+                f,
+                "response id {} doesn't match request id {}",
+                server, client
+            ),
+            JsonRpcViolation::MissingBatchResponse { client } => {
+                write!(f, "no response to batched request id {}", client)
+            }
+            JsonRpcViolation::NoResultOrError => {
+                f.write_str("response has neither a result nor an error")
+            }
+            JsonRpcViolation::ResultAndError { .. } => {
+                f.write_str("response has both a result and an error")
+            }
+        }
+    }
+}
+
+impl std::error::Error for JsonRpcViolation {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            JsonRpcViolation::MalformedJson { reason, .. } => Some(reason),
+            JsonRpcViolation::ResultAndError { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// An `InvalidRequest` occurs when the arguments of a call can't be expressed as the positional `params` that `zcashd` expects.
+#[derive(Debug)]
+pub enum InvalidRequest {
+    Unserializable {
+        method: &'static str,
+        param: &'static str,
+        reason: serde_json::Error,
+    },
+    OptionalParamGap {
+        method: &'static str,
+        omitted: &'static str,
+        given: &'static str,
+    },
+    WrongNetwork {
+        method: &'static str,
+        param: &'static str,
+        node: crate::Network,
+        address: crate::Network,
+    },
+    /// A `SendManyRequest` which `zcashd` would reject.
+    SendMany(SendManyError),
+}
+
+impl InvalidRequest {
+    /// The method whose params were invalid.
+    pub fn method(&self) -> &'static str {
+        match self {
+            InvalidRequest::Unserializable { method, .. }
+            | InvalidRequest::OptionalParamGap { method, .. }
+            | InvalidRequest::WrongNetwork { method, .. } => method,
+            InvalidRequest::SendMany(_) => "z_sendmany",
+        }
+    }
+}
+
+impl std::fmt::Display for InvalidRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidRequest::Unserializable { method, param, .. } => {
+                write!(f, "can't serialize param {} of {}", param, method)
+            }
+            InvalidRequest::OptionalParamGap {
+                method,
+                omitted,
+                given,
+            } => write!(
+                f,
+                "param {} of {} is given, but the preceding {} is omitted",
+                given, method, omitted
+            ),
+            InvalidRequest::WrongNetwork {
+                method,
+                param,
+                node,
+                address,
+            } => write!(
+                f,
+                "param {} of {} is a {} address, but the node follows {}",
+                param, method, address, node
+            ),
+            InvalidRequest::SendMany(_) => f.write_str("invalid z_sendmany"),
+        }
+    }
+}
+
+impl std::error::Error for InvalidRequest {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InvalidRequest::Unserializable { reason, .. } => Some(reason),
+            InvalidRequest::SendMany(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// A `SendManyError` is a mistake in a `SendManyRequest` which `zcashd` would reject it for.
+#[derive(Debug)]
+pub enum SendManyError {
+    NoRecipients,
+    /// `zcashd` rejects a request which lists the same address twice.
+    DuplicateAddress(crate::Address),
+    NegativeAmount(crate::Address),
+    NegativeFee,
+    /// Only shielded addresses can be sent a memo.
+    MemoToTransparent(crate::Address),
+}
+
+impl std::fmt::Display for SendManyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SendManyError::NoRecipients => f.write_str("no recipients"),
+            SendManyError::DuplicateAddress(address) => {
+                write!(f, "{} is listed more than once", address)
+            }
+            SendManyError::NegativeAmount(address) => {
+                write!(f, "the amount for {} is negative", address)
+            }
+            SendManyError::NegativeFee => f.write_str("the fee is negative"),
+            SendManyError::MemoToTransparent(address) => {
+                write!(f, "{} is transparent, so can't be sent a memo", address)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SendManyError {}
+
+/// A `MemoError` occurs when a `Memo` can't be made from the given content or hex.
+#[derive(Debug, derive_more::From)]
+pub enum MemoError {
+    /// The content is longer than the 512 bytes of a memo field.
+    #[from(ignore)]
+    TooLong {
+        len: usize,
+    },
+    InvalidHex(hex::FromHexError),
+}
+
+impl std::fmt::Display for MemoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MemoError::TooLong { len } => {
+                write!(f, "a memo holds at most 512 bytes, not {}", len)
+            }
+            MemoError::InvalidHex(_) => f.write_str("invalid memo hex"),
+        }
+    }
+}
+
+impl std::error::Error for MemoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MemoError::InvalidHex(e) => Some(e),
+            MemoError::TooLong { .. } => None,
+        }
+    }
+}
+
+/// A `TransportError` occurs in a `Transport` other than HTTP.
+#[derive(Debug)]
+pub enum TransportError {
+    /// A `RecordingTransport` can't write its cassette.
+    Cassette(std::io::Error),
+    /// A `ReplayTransport` has no unused recording of the `request`.
+    NotRecorded { request: serde_json::Value },
+    /// Any error from a `Transport` outside this crate.
+    Other(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl std::fmt::Display for TransportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TransportError::Cassette(_) => {
+                f.write_str("can't write the cassette")
+            }
+            TransportError::NotRecorded { request } => {
+                write!(f, "no recorded response to {}", request)
+            }
+            TransportError::Other(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for TransportError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TransportError::Cassette(e) => Some(e),
+            TransportError::NotRecorded { .. } => None,
+            TransportError::Other(e) => e.source(),
+        }
+    }
+}
+
+/// A `ParseHexError` occurs when a string isn't the hex encoding of a hash or byte string.
+#[derive(Debug, derive_more::From)]
+pub enum ParseHexError {
+    InvalidHex(hex::FromHexError),
+    WrongLength { expected: usize, found: usize },
+}
+
+impl std::fmt::Display for ParseHexError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseHexError::InvalidHex(_) => f.write_str("invalid hex"),
+            ParseHexError::WrongLength { expected, found } => {
+                write!(f, "expected {} bytes of hex, found {}", expected, found)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseHexError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseHexError::InvalidHex(e) => Some(e),
+            ParseHexError::WrongLength { .. } => None,
+        }
+    }
+}
+
+/// A `ParseAddressError` occurs when a string isn't a valid encoding of any `Address` type.
+#[derive(Debug, derive_more::From)]
+pub enum ParseAddressError {
+    Base58(bs58::decode::Error),
+    Bech32(bech32::Error),
+    UnknownPrefix,
+    WrongLength { expected: usize, found: usize },
+    WrongVariant,
+}
+
+impl std::fmt::Display for ParseAddressError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseAddressError::Base58(_) => f.write_str("invalid base58check"),
+            ParseAddressError::Bech32(_) => f.write_str("invalid bech32"),
+            ParseAddressError::UnknownPrefix => {
+                f.write_str("unknown address prefix")
+            }
+            ParseAddressError::WrongLength { expected, found } => write!(
+                f,
+                "expected an address payload of {} bytes, found {}",
+                expected, found
+            ),
+            ParseAddressError::WrongVariant => f.write_str(
+                "wrong bech32 checksum variant for the address type",
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseAddressError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseAddressError::Base58(e) => Some(e),
+            ParseAddressError::Bech32(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// A `ParseAmountError` occurs when a value isn't a valid `ZecAmount`.
+#[derive(Debug)]
+pub enum ParseAmountError {
+    /// The value isn't a decimal number.
+    Malformed,
+    /// The value is more precise than one zatoshi.
+    TooPrecise,
+    /// The value is beyond `MAX_MONEY`, either way from zero.
+    OutOfRange,
+}
+
+impl std::fmt::Display for ParseAmountError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ParseAmountError::Malformed => "amount is not a decimal number",
+            ParseAmountError::TooPrecise => {
+                "amount is more precise than one zatoshi"
+            }
+            ParseAmountError::OutOfRange => "amount is beyond MAX_MONEY",
+        })
+    }
+}
+
+impl std::error::Error for ParseAmountError {}
+
+/// A `BuildError` occurs when a `ClientBuilder` is given settings which can't make a `Client`.
+#[derive(Debug, derive_more::From)]
+pub enum BuildError {
+    InvalidUrl(url::ParseError),
+    /// Only `http` and `https` URLs are supported.
+    #[from(ignore)]
+    UnsupportedScheme(String),
+    /// The named setting configures a `reqwest::Client`, so it can't be combined with `ClientBuilder::reqwest_client`.
+    #[from(ignore)]
+    ConflictsWithClient(&'static str),
+    Http(reqwest::Error),
+}
+
+impl std::fmt::Display for BuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BuildError::InvalidUrl(_) => f.write_str("invalid url"),
+            BuildError::UnsupportedScheme(scheme) => {
+                write!(f, "unsupported url scheme {:?}", scheme)
+            }
+            BuildError::ConflictsWithClient(setting) => write!(
+                f,
+                "{} can't be combined with a custom reqwest client",
+                setting
+            ),
+            BuildError::Http(_) => f.write_str("can't build the http client"),
+        }
+    }
+}
+
+impl std::error::Error for BuildError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BuildError::InvalidUrl(e) => Some(e),
+            BuildError::Http(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// A `ConfError` occurs when a `zcash.conf` can't be read, or its settings can't make a `Client`.
+#[derive(Debug, derive_more::From)]
+pub enum ConfError {
+    /// No `-datadir` was given, and the home directory containing the default `~/.zcash` is unknown.
+    NoDatadir,
+    #[from(ignore)]
+    Read {
+        path: std::path::PathBuf,
+        reason: std::io::Error,
+    },
+    /// The line, counting from 1, is neither a comment nor a `key=value` setting.
+    #[from(ignore)]
+    Malformed {
+        line: usize,
+    },
+    #[from(ignore)]
+    InvalidValue {
+        key: &'static str,
+        value: String,
+    },
+    /// Both `testnet` and `regtest` are set.
+    ConflictingNetworks,
+    Build(BuildError),
+}
+
+impl std::fmt::Display for ConfError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfError::NoDatadir => {
+                f.write_str("no datadir given, and no home directory found")
+            }
+            ConfError::Read { path, .. } => {
+                write!(f, "can't read {}", path.display())
+            }
+            ConfError::Malformed { line } => {
+                write!(f, "line {} is not a key=value setting", line)
+            }
+            ConfError::InvalidValue { key, value } => {
+                write!(f, "invalid {}: {:?}", key, value)
+            }
+            ConfError::ConflictingNetworks => {
+                f.write_str("testnet and regtest can't both be set")
+            }
+            ConfError::Build(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for ConfError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfError::Read { reason, .. } => Some(reason),
+            ConfError::Build(e) => e.source(),
+            _ => None,
+        }
+    }
+}
+
+/// A `ParseNetworkError` occurs when a chain name isn't one of `main`, `test` or `regtest`.
+#[derive(Debug)]
+pub struct ParseNetworkError {
+    pub name: String,
+}
+
+impl std::fmt::Display for ParseNetworkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown network {:?}", self.name)
+    }
+}
+
+impl std::error::Error for ParseNetworkError {}
+
+/// A `ParseRescanError` occurs when a `rescan` value isn't one of `yes`, `no` or `whenkeyisnew`.
+#[derive(Debug)]
+pub struct ParseRescanError {
+    pub value: String,
+}
+
+impl std::fmt::Display for ParseRescanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown rescan value {:?}", self.value)
+    }
+}
+
+impl std::error::Error for ParseRescanError {}
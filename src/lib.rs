@@ -1,15 +1,61 @@
 //! An asynchronous zcashd RPC client.
 
+#[macro_use]
+mod serde_via_str;
+pub mod address;
+pub mod amount;
+pub mod auth;
+pub mod bytes;
 pub mod client;
+pub mod config;
 mod envelope;
 pub mod error;
 mod json;
+pub mod memo;
+#[cfg(feature = "mock")]
+pub mod mock;
+pub mod network;
+pub mod operation;
+pub mod stream;
+pub mod wallet;
 
 #[doc(inline)]
-pub use client::Client;
+pub use client::{builder::ClientBuilder, retry::RetryPolicy, Client};
 
 #[doc(inline)]
-pub use error::{Error, ResponseResult};
+pub use client::transport::{
+    HttpTransport, RecordingTransport, ReplayTransport, Transport,
+};
 
-/// The `ZecAmount` type alias is used to document where ZEC-denominated fields are used. Note that this does not represent Zatoshi-denominated units.
-pub type ZecAmount = rust_decimal::Decimal;
+#[doc(inline)]
+pub use config::ZcashConf;
+
+#[doc(inline)]
+pub use error::{Error, ResponseResult, RpcErrorCode};
+
+#[doc(inline)]
+pub use address::Address;
+
+#[doc(inline)]
+pub use amount::{Zatoshis, ZecAmount};
+
+#[doc(inline)]
+pub use auth::Auth;
+
+#[doc(inline)]
+pub use bytes::{BlockHash, HexBytes, MerkleRoot, TxId};
+
+#[doc(inline)]
+pub use network::Network;
+
+#[doc(inline)]
+pub use operation::{Operation, OperationId};
+
+#[doc(inline)]
+pub use stream::{BlockEvent, BlockStream, Checkpoint};
+
+#[doc(inline)]
+pub use memo::{Memo, MemoContent};
+
+#[doc(inline)]
+pub use wallet::{Recipient, Rescan, SendManyRequest};
@@ -0,0 +1,122 @@
+//! The `memo` mod includes `Memo`, the 512 bytes a shielded output carries along with its value, and `MemoContent`, which interprets them as ZIP 302 specifies.
+
+use crate::error::MemoError;
+use std::fmt;
+use std::str::FromStr;
+
+/// The number of bytes in a memo field. Shorter memos are padded with zeros.
+pub const MEMO_SIZE: usize = 512;
+
+/// The first byte of the memo of a sender who didn't write one.
+const EMPTY_MARKER: u8 = 0xF6;
+
+/// The first byte of a memo holding arbitrary data rather than text.
+const ARBITRARY_MARKER: u8 = 0xFF;
+
+/// A `Memo` is the content of a shielded output's memo field, which `zcashd` encodes as 1024 hex digits. Memos which differ only in their zero padding are equal.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Memo(Vec<u8>);
+
+/// The meaning of a `Memo`, by the format ZIP 302 assigns to its first byte.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MemoContent {
+    /// The sender didn't write a memo: `0xF6` followed by zeros.
+    Empty,
+    /// UTF-8 text, without the zeros which pad it.
+    Text(String),
+    /// A first byte of `0xF4` or less, which marks text, followed by bytes which aren't UTF-8.
+    InvalidText(Vec<u8>),
+    /// The 511 bytes after `0xFF`, which are data for an application rather than text to show the user.
+    Arbitrary(Vec<u8>),
+    /// All 512 bytes of a memo in a format ZIP 302 reserves for future use.
+    Reserved(Vec<u8>),
+}
+
+impl Memo {
+    /// The memo `zcashd` sends when none is given, which means the sender didn't write one.
+    pub fn empty() -> Memo {
+        Memo(vec![EMPTY_MARKER])
+    }
+
+    /// A memo of `text`, encoded as UTF-8, which must fit in `MEMO_SIZE` bytes.
+    pub fn from_text(text: &str) -> Result<Memo, MemoError> {
+        Memo::from_bytes(text.as_bytes())
+    }
+
+    /// A memo of arbitrary `data` for an application, which must fit in the 511 bytes after the `0xFF` which marks it.
+    pub fn arbitrary(data: &[u8]) -> Result<Memo, MemoError> {
+        let mut bytes = vec![ARBITRARY_MARKER];
+        bytes.extend_from_slice(data);
+        Memo::from_bytes(&bytes)
+    }
+
+    /// A memo of `bytes`, which must fit in `MEMO_SIZE` bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Memo, MemoError> {
+        if bytes.len() > MEMO_SIZE {
+            return Err(MemoError::TooLong { len: bytes.len() });
+        }
+        let unpadded = bytes.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+        Ok(Memo(bytes[..unpadded].to_vec()))
+    }
+
+    /// The bytes of the memo, without its zero padding.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// All `MEMO_SIZE` bytes of the memo field, padded with zeros.
+    pub fn to_bytes(&self) -> [u8; MEMO_SIZE] {
+        let mut bytes = [0; MEMO_SIZE];
+        bytes[..self.0.len()].copy_from_slice(&self.0);
+        bytes
+    }
+
+    /// Interpret the memo as ZIP 302 specifies.
+    pub fn content(&self) -> MemoContent {
+        match self.0.first() {
+            None => MemoContent::Text(String::new()),
+            Some(&first) if first <= 0xF4 => {
+                match String::from_utf8(self.0.clone()) {
+                    Ok(text) => MemoContent::Text(text),
+                    Err(e) => MemoContent::InvalidText(e.into_bytes()),
+                }
+            }
+            Some(&EMPTY_MARKER) if self.0.len() == 1 => MemoContent::Empty,
+            Some(&ARBITRARY_MARKER) => {
+                MemoContent::Arbitrary(self.to_bytes()[1..].to_vec())
+            }
+            Some(_) => MemoContent::Reserved(self.to_bytes().to_vec()),
+        }
+    }
+
+    /// The text of a text memo.
+    pub fn text(&self) -> Option<String> {
+        match self.content() {
+            MemoContent::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Whether the sender didn't write a memo.
+    pub fn is_empty(&self) -> bool {
+        self.content() == MemoContent::Empty
+    }
+}
+
+/// Displays all 1024 hex digits of the memo field, as `zcashd` does.
+impl fmt::Display for Memo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&hex::encode(&self.to_bytes()[..]))
+    }
+}
+
+/// Parses the hex of up to `MEMO_SIZE` bytes, padded with zeros or not.
+impl FromStr for Memo {
+    type Err = MemoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Memo::from_bytes(&hex::decode(s)?)
+    }
+}
+
+impl_serde_via_str!(Memo);
@@ -0,0 +1,319 @@
+//! The `mock` mod includes `MockZcashd`, a JSON-RPC server on localhost which authenticates and answers like `zcashd`, so that code using a `Client` can be tested without a node. It is only built with the `mock` feature.
+
+use crate::error::{ResponseError, RpcErrorCode};
+use crate::{Auth, Client, Network};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io::{BufRead as _, BufReader, Read as _, Write as _};
+use std::net::{TcpListener, TcpStream};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// A `Handler` answers a method's positional `params` with its `result`, or with the error `zcashd` would respond with.
+pub type Handler =
+    Box<dyn Fn(&[Value]) -> Result<Value, ResponseError> + Send + Sync>;
+
+/// A `MockRequest` is one call the mock has received, in a single request or a batch.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MockRequest {
+    pub method: String,
+    pub params: Vec<Value>,
+}
+
+/// A `MockZcashd` serves JSON-RPC on a free port on localhost until it is dropped. Like `zcashd`, it responds with HTTP 401 to requests without the right credentials, answers batches, and responds to unknown methods with `RpcErrorCode::MethodNotFound` and HTTP 404, and to failed calls with HTTP 500. Methods are answered by the handlers and fixtures registered on it, which can be changed while it runs.
+pub struct MockZcashd {
+    addr: std::net::SocketAddr,
+    url: String,
+    user: String,
+    password: String,
+    state: Arc<State>,
+}
+
+struct State {
+    authorization: String,
+    handlers: Mutex<HashMap<String, Arc<Handler>>>,
+    requests: Mutex<Vec<MockRequest>>,
+    stopped: AtomicBool,
+}
+
+impl MockZcashd {
+    /// Start a mock which authenticates clients with a random cookie, as `zcashd` does without `rpcpassword`.
+    pub fn start() -> std::io::Result<MockZcashd> {
+        MockZcashd::start_with_credentials("__cookie__", &random_password())
+    }
+
+    /// Start a mock which authenticates clients as `user` with `password`, as `zcashd` does with `rpcuser` and `rpcpassword`.
+    pub fn start_with_credentials(
+        user: &str,
+        password: &str,
+    ) -> std::io::Result<MockZcashd> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        let url = format!("http://{}/", addr);
+        let state = Arc::new(State {
+            authorization: crate::auth::basic_auth(user, password),
+            handlers: Mutex::new(HashMap::new()),
+            requests: Mutex::new(Vec::new()),
+            stopped: AtomicBool::new(false),
+        });
+        let accepting = state.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                if accepting.stopped.load(Ordering::SeqCst) {
+                    break;
+                }
+                if let Ok(stream) = stream {
+                    let state = accepting.clone();
+                    std::thread::spawn(move || serve_connection(stream, state));
+                }
+            }
+        });
+        Ok(MockZcashd {
+            addr,
+            url,
+            user: user.to_string(),
+            password: password.to_string(),
+            state,
+        })
+    }
+
+    /// The URL the mock serves on, e.g. for `Client::builder`.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// The credentials the mock accepts.
+    pub fn auth(&self) -> Auth {
+        Auth::UserPass {
+            user: self.user.clone(),
+            password: self.password.clone(),
+        }
+    }
+
+    /// A `Client` for the mock, with its credentials and `Network::Regtest`.
+    pub fn client(&self) -> Client {
+        Client::builder(self.url.as_str())
+            .auth(self.auth())
+            .network(Network::Regtest)
+            .build()
+            .unwrap()
+    }
+
+    /// Answer calls to `method` with `handler`, replacing any earlier handler or fixture for it.
+    pub fn handle(
+        &self,
+        method: &str,
+        handler: impl Fn(&[Value]) -> Result<Value, ResponseError>
+            + Send
+            + Sync
+            + 'static,
+    ) {
+        self.state
+            .handlers
+            .lock()
+            .unwrap()
+            .insert(method.to_string(), Arc::new(Box::new(handler)));
+    }
+
+    /// Answer every call to `method` with `result`, whatever its params.
+    pub fn fixture(&self, method: &str, result: Value) {
+        self.handle(method, move |_| Ok(result.clone()));
+    }
+
+    /// Answer every call to `method` with the result in the JSON file at `path`, e.g. one of the crate's `json_data` fixtures.
+    pub fn fixture_file(
+        &self,
+        method: &str,
+        path: impl AsRef<Path>,
+    ) -> std::io::Result<()> {
+        let text = std::fs::read_to_string(path)?;
+        let result = serde_json::from_str(&text).map_err(|e| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, e)
+        })?;
+        self.fixture(method, result);
+        Ok(())
+    }
+
+    /// Respond to every call to `method` with the error `code` and `message`.
+    pub fn fail(&self, method: &str, code: RpcErrorCode, message: &str) {
+        let message = message.to_string();
+        self.handle(method, move |_| {
+            Err(ResponseError {
+                code,
+                message: message.clone(),
+            })
+        });
+    }
+
+    /// Every call received so far, in the order they were received.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.state.requests.lock().unwrap().clone()
+    }
+}
+
+/// Stops accepting connections. Connections which are already open are served until the client closes them.
+impl Drop for MockZcashd {
+    fn drop(&mut self) {
+        self.state.stopped.store(true, Ordering::SeqCst);
+        // Wake the accepting thread so that it sees it has been stopped.
+        let _ = TcpStream::connect(self.addr);
+    }
+}
+
+/// Serve the requests on `stream` until the client closes it.
+fn serve_connection(stream: TcpStream, state: Arc<State>) {
+    let mut reader = BufReader::new(match stream.try_clone() {
+        Ok(stream) => stream,
+        Err(_) => return,
+    });
+    let mut writer = stream;
+    while let Some((authorization, body)) = read_request(&mut reader) {
+        let (status, body) =
+            if authorization.as_deref() != Some(state.authorization.as_str()) {
+                ("401 Unauthorized", String::new())
+            } else {
+                respond(&state, &body)
+            };
+        let head = format!(
+            "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n{}\r\n",
+            status,
+            body.len(),
+            if status.starts_with("401") {
+                "WWW-Authenticate: Basic realm=\"jsonrpc\"\r\n"
+            } else {
+                ""
+            }
+        );
+        if writer.write_all(head.as_bytes()).is_err()
+            || writer.write_all(body.as_bytes()).is_err()
+        {
+            return;
+        }
+    }
+}
+
+/// Read the next request on a connection, returning its `Authorization` header and body, or `None` once the connection is closed.
+fn read_request(
+    reader: &mut BufReader<TcpStream>,
+) -> Option<(Option<String>, String)> {
+    let mut authorization = None;
+    let mut content_length = 0;
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).ok()? == 0 {
+        return None;
+    }
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).ok()? == 0 {
+            return None;
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some(colon) = line.find(':') {
+            let value = line[colon + 1..].trim();
+            match line[..colon].to_ascii_lowercase().as_str() {
+                "authorization" => authorization = Some(value.to_string()),
+                "content-length" => content_length = value.parse().ok()?,
+                _ => (),
+            }
+        }
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).ok()?;
+    Some((authorization, String::from_utf8_lossy(&body).into_owned()))
+}
+
+/// The HTTP status and body `zcashd` would respond to `body` with. A batch is answered with 200 whatever its calls' outcomes, and a single call with a status which reflects its error, if any.
+fn respond(state: &State, body: &str) -> (&'static str, String) {
+    match serde_json::from_str(body) {
+        Ok(Value::Array(calls)) => {
+            let responses: Vec<Value> = calls
+                .iter()
+                .map(|call| {
+                    let (id, outcome) = answer(state, call);
+                    envelope(&id, outcome)
+                })
+                .collect();
+            ("200 OK", Value::Array(responses).to_string())
+        }
+        Ok(call) => {
+            let (id, outcome) = answer(state, &call);
+            (status(&outcome), envelope(&id, outcome).to_string())
+        }
+        Err(e) => {
+            let outcome = Err(error(RpcErrorCode::ParseError, &e.to_string()));
+            (
+                status(&outcome),
+                envelope(&Value::Null, outcome).to_string(),
+            )
+        }
+    }
+}
+
+/// The id of a single `call`, and the outcome of calling its handler.
+fn answer(
+    state: &State,
+    call: &Value,
+) -> (Value, Result<Value, ResponseError>) {
+    let id = call.get("id").cloned().unwrap_or(Value::Null);
+    let method = match call.get("method").and_then(Value::as_str) {
+        Some(method) => method,
+        None => {
+            let message = "Method must be a string";
+            return (id, Err(error(RpcErrorCode::InvalidRequest, message)));
+        }
+    };
+    let params = match call.get("params") {
+        Some(Value::Array(params)) => params.clone(),
+        None | Some(Value::Null) => Vec::new(),
+        Some(_) => {
+            let message = "Params must be an array";
+            return (id, Err(error(RpcErrorCode::InvalidRequest, message)));
+        }
+    };
+    state.requests.lock().unwrap().push(MockRequest {
+        method: method.to_string(),
+        params: params.clone(),
+    });
+    let handler = state.handlers.lock().unwrap().get(method).cloned();
+    let outcome = match handler {
+        Some(handler) => handler(&params),
+        None => Err(error(RpcErrorCode::MethodNotFound, "Method not found")),
+    };
+    (id, outcome)
+}
+
+/// The HTTP status `zcashd` responds to a single call with.
+fn status(outcome: &Result<Value, ResponseError>) -> &'static str {
+    match outcome {
+        Ok(_) => "200 OK",
+        Err(e) => match e.code {
+            RpcErrorCode::InvalidRequest => "400 Bad Request",
+            RpcErrorCode::MethodNotFound => "404 Not Found",
+            _ => "500 Internal Server Error",
+        },
+    }
+}
+
+fn envelope(id: &Value, outcome: Result<Value, ResponseError>) -> Value {
+    match outcome {
+        Ok(result) => json!({"result": result, "error": null, "id": id}),
+        Err(error) => json!({"result": null, "error": error, "id": id}),
+    }
+}
+
+fn error(code: RpcErrorCode, message: &str) -> ResponseError {
+    ResponseError {
+        code,
+        message: message.to_string(),
+    }
+}
+
+/// A password which is random enough for a test server.
+fn random_password() -> String {
+    let random_u64 = crate::client::utils::random_u64;
+    format!("{:016x}{:016x}", random_u64(), random_u64())
+}
@@ -0,0 +1,61 @@
+//! The `network` mod includes `Network`, which identifies the chain a `zcashd` node follows.
+
+use crate::error::ParseNetworkError;
+use std::fmt;
+use std::str::FromStr;
+
+/// A `Network` is the chain a `zcashd` node follows, and the chain an address is encoded for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Network {
+    Main,
+    Test,
+    Regtest,
+}
+
+impl Network {
+    /// The port `zcashd` serves RPC on, unless `rpcport` is set.
+    pub fn default_rpc_port(self) -> u16 {
+        match self {
+            Network::Main => 8232,
+            Network::Test | Network::Regtest => 18232,
+        }
+    }
+
+    /// The subdirectory of the data directory where `zcashd` keeps the chain state and `.cookie` for the network, if it isn't the data directory itself.
+    pub fn datadir_subdirectory(self) -> Option<&'static str> {
+        match self {
+            Network::Main => None,
+            Network::Test => Some("testnet3"),
+            Network::Regtest => Some("regtest"),
+        }
+    }
+}
+
+/// Displays the name `getblockchaininfo` reports as `chain`.
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Network::Main => "main",
+            Network::Test => "test",
+            Network::Regtest => "regtest",
+        })
+    }
+}
+
+/// Parses the name `getblockchaininfo` reports as `chain`.
+impl FromStr for Network {
+    type Err = ParseNetworkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "main" => Ok(Network::Main),
+            "test" => Ok(Network::Test),
+            "regtest" => Ok(Network::Regtest),
+            _ => Err(ParseNetworkError {
+                name: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl_serde_via_str!(Network);
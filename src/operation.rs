@@ -0,0 +1,191 @@
+//! The `operation` mod includes `Operation`, a handle on one of the asynchronous operations `zcashd` runs for `z_sendmany`, `z_shieldcoinbase` and `z_mergetoaddress`, and the types `z_getoperationstatus` and `z_getoperationresult` respond with.
+
+use crate::error::{OperationFailure, ResponseError};
+use crate::{Client, ResponseResult, TxId};
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// An `OperationId` is the `opid` `zcashd` returns when it starts an asynchronous operation, e.g. `opid-f8e3d2a3-...`.
+#[derive(
+    Clone, Debug, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize,
+)]
+#[serde(transparent)]
+pub struct OperationId(pub String);
+
+impl fmt::Display for OperationId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for OperationId {
+    fn from(opid: String) -> Self {
+        OperationId(opid)
+    }
+}
+
+/// An `OperationState` is the `status` of an operation. `Success`, `Failed` and `Cancelled` are final.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Deserialize,
+    serde::Serialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum OperationState {
+    Queued,
+    Executing,
+    Success,
+    Failed,
+    Cancelled,
+}
+
+impl OperationState {
+    /// Whether the operation has finished, so its state won't change again.
+    pub fn is_final(self) -> bool {
+        !matches!(self, OperationState::Queued | OperationState::Executing)
+    }
+}
+
+/// An `OperationStatus` is an entry of the responses to `z_getoperationstatus` and `z_getoperationresult`.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct OperationStatus {
+    pub id: OperationId,
+    pub status: OperationState,
+    pub creation_time: u64,
+    /// The RPC method which started the operation, e.g. `z_sendmany`.
+    pub method: String,
+    /// The params the operation was started with, as `zcashd` reports them.
+    #[serde(default)]
+    pub params: Option<serde_json::Value>,
+    /// The outcome of a successful operation, which for the operations which send a transaction has its `txid`.
+    #[serde(default)]
+    pub result: Option<serde_json::Value>,
+    /// Why a failed operation failed.
+    #[serde(default)]
+    pub error: Option<ResponseError>,
+    /// How long a finished operation ran for.
+    #[serde(default)]
+    pub execution_secs: Option<f64>,
+}
+
+impl OperationStatus {
+    /// The `txid` of the transaction a successful operation sent.
+    pub fn txid(&self) -> Option<TxId> {
+        let txid = self.result.as_ref()?.get("txid")?;
+        serde_json::from_value(txid.clone()).ok()
+    }
+}
+
+/// An `Operation` is a handle on an asynchronous operation, which polls `zcashd` until the operation finishes. Its requests share the connection and settings of the `Client` which started it, but not its borrow, so any number of operations can be awaited at once.
+pub struct Operation {
+    client: Client,
+    opid: OperationId,
+    interval: Duration,
+    timeout: Option<Duration>,
+}
+
+impl Operation {
+    pub(crate) fn new(client: Client, opid: OperationId) -> Operation {
+        Operation {
+            client,
+            opid,
+            interval: Duration::from_secs(1),
+            timeout: None,
+        }
+    }
+
+    pub fn id(&self) -> &OperationId {
+        &self.opid
+    }
+
+    /// Poll the operation's status every `interval`, rather than every second.
+    pub fn interval(mut self, interval: Duration) -> Operation {
+        self.interval = interval;
+        self
+    }
+
+    /// Stop waiting once `timeout` has passed since `wait` was called, with `OperationFailure::TimedOut`. Without a timeout, `wait` polls until the operation finishes.
+    pub fn timeout(mut self, timeout: Duration) -> Operation {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// The current status of the operation, with `OperationFailure::Unknown` if `zcashd` has no record of it.
+    pub async fn status(&mut self) -> ResponseResult<OperationStatus> {
+        let statuses = self
+            .client
+            .z_getoperationstatus(Some(vec![self.opid.clone()]))
+            .await?;
+        self.find(statuses)
+    }
+
+    /// Poll until the operation finishes, and then remove it from `zcashd`'s list of operations with `z_getoperationresult`. Resolves to the `txid` the operation sent, or an `Error::Operation` saying why it didn't.
+    pub async fn wait(mut self) -> ResponseResult<TxId> {
+        let started = Instant::now();
+        loop {
+            let state = self.status().await?.status;
+            if state.is_final() {
+                break;
+            }
+            if let Some(timeout) = self.timeout {
+                if started.elapsed() + self.interval > timeout {
+                    return Err(
+                        self.failure(OperationFailure::TimedOut { state })
+                    );
+                }
+            }
+            tokio::time::delay_for(self.interval).await;
+        }
+        let results = self
+            .client
+            .z_getoperationresult(Some(vec![self.opid.clone()]))
+            .await?;
+        let finished = self.find(results)?;
+        match finished.status {
+            OperationState::Success => finished
+                .txid()
+                .ok_or_else(|| self.failure(OperationFailure::MissingTxid)),
+            OperationState::Cancelled => {
+                Err(self.failure(OperationFailure::Cancelled))
+            }
+            _ => Err(self.failure(OperationFailure::Failed(
+                finished.error.unwrap_or_else(|| ResponseError {
+                    code: crate::RpcErrorCode::MiscError,
+                    message: String::from("no error reported"),
+                }),
+            ))),
+        }
+    }
+
+    fn find(
+        &self,
+        statuses: Vec<OperationStatus>,
+    ) -> ResponseResult<OperationStatus> {
+        statuses
+            .into_iter()
+            .find(|status| status.id == self.opid)
+            .ok_or_else(|| self.failure(OperationFailure::Unknown))
+    }
+
+    fn failure(&self, failure: OperationFailure) -> crate::Error {
+        crate::Error::Operation {
+            opid: self.opid.clone(),
+            failure,
+        }
+    }
+}
+
+impl fmt::Debug for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Operation")
+            .field("opid", &self.opid)
+            .field("interval", &self.interval)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
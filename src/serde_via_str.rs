@@ -0,0 +1,23 @@
+/// Implement `Serialize` as the `Display` string and `Deserialize` with `FromStr`, which is how `zcashd` represents hashes, hex and addresses in JSON.
+macro_rules! impl_serde_via_str {
+    ($name:ident) => {
+        impl serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let s = String::deserialize(deserializer)?;
+                s.parse().map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
@@ -0,0 +1,229 @@
+//! The `stream` mod includes `BlockStream`, a `futures::Stream` of the blocks of `zcashd`'s best chain which follows its tip and reports reorgs, and the `BlockEvent`s it yields.
+
+use crate::client::subcomponents::getblock::Verbose;
+use crate::{BlockHash, Client, Error, ResponseResult};
+use futures::Stream;
+use std::collections::VecDeque;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+/// How many of the blocks it has yielded a `BlockStream` remembers, to find where a reorg forked from. `zcashd` refuses reorgs longer than 99 blocks.
+const REMEMBERED: usize = 100;
+
+/// A `Checkpoint` is the height and hash of a block a `BlockStream` has yielded, which an indexer can store to resume the stream from later.
+#[derive(
+    Clone, Debug, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize,
+)]
+pub struct Checkpoint {
+    pub height: u32,
+    pub hash: BlockHash,
+}
+
+impl Checkpoint {
+    fn of(block: &Verbose) -> Checkpoint {
+        Checkpoint {
+            height: block.height,
+            hash: block.hash,
+        }
+    }
+}
+
+/// A `BlockEvent` is a change to the best chain a `BlockStream` follows.
+#[derive(Debug)]
+pub enum BlockEvent {
+    /// The next block of the chain, whose parent is the block yielded before it.
+    Block(Box<Verbose>),
+    /// The node switched to a chain which no longer includes the `disconnected` blocks, which were yielded earlier. The chain continues from their parent with the `connected` blocks instead. Both are in order of height, and `connected` is empty when the node's tip moved back onto a block already yielded.
+    Reorg {
+        disconnected: Vec<Checkpoint>,
+        connected: Vec<Verbose>,
+    },
+}
+
+type Step = Pin<
+    Box<dyn Future<Output = (Follower, ResponseResult<BlockEvent>)> + Send>,
+>;
+
+/// A `BlockStream` yields the blocks of the node's best chain in order of height, polling `getblockcount` every `interval` once it reaches the tip. Its requests share the connection and settings of the `Client` which made it. A failed request is yielded as an error, and the stream retries it when it is polled again, so it never ends.
+pub struct BlockStream {
+    follower: Option<Follower>,
+    step: Option<Step>,
+}
+
+struct Follower {
+    client: Client,
+    next_height: u32,
+    interval: Duration,
+    yielded: VecDeque<Checkpoint>,
+}
+
+impl BlockStream {
+    pub(crate) fn new(client: Client, start_height: u32) -> BlockStream {
+        BlockStream {
+            follower: Some(Follower {
+                client,
+                next_height: start_height,
+                interval: Duration::from_secs(1),
+                yielded: VecDeque::new(),
+            }),
+            step: None,
+        }
+    }
+
+    /// Poll for new blocks every `interval` at the tip, rather than every second.
+    pub fn interval(mut self, interval: Duration) -> BlockStream {
+        if let Some(follower) = &mut self.follower {
+            follower.interval = interval;
+        }
+        self
+    }
+
+    /// Continue after `checkpoint`, a block yielded by an earlier stream, rather than from the start height. If a reorg has since disconnected it, the stream can't tell where the chain forked from, and yields `Error::ReorgTooDeep`.
+    pub fn resume(mut self, checkpoint: Checkpoint) -> BlockStream {
+        if let Some(follower) = &mut self.follower {
+            follower.next_height = checkpoint.height + 1;
+            follower.yielded = VecDeque::from(vec![checkpoint]);
+        }
+        self
+    }
+
+    /// The last block the stream yielded, or the one it resumed from. `None` while the stream is being polled.
+    pub fn checkpoint(&self) -> Option<&Checkpoint> {
+        self.follower.as_ref()?.yielded.back()
+    }
+}
+
+impl Stream for BlockStream {
+    type Item = ResponseResult<BlockEvent>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        if let Some(follower) = this.follower.take() {
+            this.step = Some(Box::pin(follower.step()));
+        }
+        let step = match &mut this.step {
+            Some(step) => step,
+            None => return Poll::Ready(None),
+        };
+        match step.as_mut().poll(cx) {
+            Poll::Ready((follower, event)) => {
+                this.step = None;
+                this.follower = Some(follower);
+                Poll::Ready(Some(event))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl fmt::Debug for BlockStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("BlockStream");
+        if let Some(follower) = &self.follower {
+            debug
+                .field("next_height", &follower.next_height)
+                .field("interval", &follower.interval)
+                .field("checkpoint", &follower.yielded.back());
+        }
+        debug.finish()
+    }
+}
+
+impl Follower {
+    /// Wait for the next event. The follower is only changed if there is one, so a failed step can be retried.
+    async fn step(mut self) -> (Follower, ResponseResult<BlockEvent>) {
+        let event = self.next_event().await;
+        (self, event)
+    }
+
+    async fn next_event(&mut self) -> ResponseResult<BlockEvent> {
+        loop {
+            if self.client.getblockcount().await? >= self.next_height {
+                let height = self.next_height.to_string();
+                let block = self.client.getblock_verbose(height).await?;
+                return self.follow(block).await;
+            }
+            if let Some(last) = self.yielded.back() {
+                let best = self.client.getbestblockhash().await?;
+                if best != last.hash {
+                    let tip = self.client.getblock_verbose(best.to_string());
+                    let tip = tip.await?;
+                    // Otherwise a block arrived since `getblockcount`, and is fetched by height.
+                    if tip.height < self.next_height {
+                        return self.reorg(tip).await;
+                    }
+                    continue;
+                }
+            }
+            tokio::time::delay_for(self.interval).await;
+        }
+    }
+
+    async fn follow(&mut self, block: Verbose) -> ResponseResult<BlockEvent> {
+        match self.yielded.back() {
+            Some(last) if block.previousblockhash != Some(last.hash) => {
+                self.reorg(block).await
+            }
+            _ => {
+                self.next_height = block.height + 1;
+                self.yielded.push_back(Checkpoint::of(&block));
+                self.forget_old();
+                Ok(BlockEvent::Block(Box::new(block)))
+            }
+        }
+    }
+
+    /// Walk back from `tip`, a block of the node's best chain, to the last block yielded which is still its ancestor.
+    async fn reorg(&mut self, tip: Verbose) -> ResponseResult<BlockEvent> {
+        let mut yielded = self.yielded.clone();
+        let mut disconnected = Vec::new();
+        let mut connected = VecDeque::new();
+        if let Some(i) = yielded.iter().position(|c| c.hash == tip.hash) {
+            disconnected.extend(yielded.drain(i + 1..).rev());
+        } else {
+            connected.push_front(tip);
+        }
+        while let Some(first) = connected.front() {
+            let height = first.height;
+            while yielded.back().is_some_and(|c| c.height >= height) {
+                disconnected.extend(yielded.pop_back());
+            }
+            let parent = match (yielded.back(), first.previousblockhash) {
+                (Some(last), Some(parent)) if parent != last.hash => parent,
+                (Some(_), Some(_)) => break,
+                _ => {
+                    return Err(Error::ReorgTooDeep {
+                        depth: disconnected.len(),
+                    })
+                }
+            };
+            let parent = self.client.getblock_verbose(parent.to_string());
+            connected.push_front(parent.await?);
+        }
+        disconnected.reverse();
+        let connected = Vec::from(connected);
+        yielded.extend(connected.iter().map(Checkpoint::of));
+        self.next_height = match yielded.back() {
+            Some(last) => last.height + 1,
+            None => self.next_height,
+        };
+        self.yielded = yielded;
+        self.forget_old();
+        Ok(BlockEvent::Reorg {
+            disconnected,
+            connected,
+        })
+    }
+
+    fn forget_old(&mut self) {
+        while self.yielded.len() > REMEMBERED {
+            self.yielded.pop_front();
+        }
+    }
+}
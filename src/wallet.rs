@@ -0,0 +1,167 @@
+//! The `wallet` mod includes types for the params of `zcashd`'s shielded wallet methods.
+
+use crate::address::AddressParam;
+use crate::error::{ParseRescanError, SendManyError};
+use crate::{Address, Memo, ZecAmount};
+use std::fmt;
+use std::str::FromStr;
+
+/// A `Rescan` says whether `z_importkey` and `z_importviewingkey` rescan the chain for the imported key's notes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Rescan {
+    Yes,
+    No,
+    /// Rescan only if the wallet didn't already have the key, which is `zcashd`'s default.
+    WhenKeyIsNew,
+}
+
+/// Displays the value `zcashd` expects for the `rescan` param.
+impl fmt::Display for Rescan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Rescan::Yes => "yes",
+            Rescan::No => "no",
+            Rescan::WhenKeyIsNew => "whenkeyisnew",
+        })
+    }
+}
+
+impl FromStr for Rescan {
+    type Err = ParseRescanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "yes" => Ok(Rescan::Yes),
+            "no" => Ok(Rescan::No),
+            "whenkeyisnew" => Ok(Rescan::WhenKeyIsNew),
+            _ => Err(ParseRescanError {
+                value: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl_serde_via_str!(Rescan);
+
+/// A `Recipient` is one of the outputs of `z_sendmany`, serialized as the `{address, amount, memo}` object `zcashd` expects in its `amounts` param.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+pub struct Recipient {
+    pub address: Address,
+    pub amount: ZecAmount,
+    /// Only shielded recipients can be sent a memo.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<Memo>,
+}
+
+impl Recipient {
+    pub fn new(address: Address, amount: ZecAmount) -> Recipient {
+        Recipient {
+            address,
+            amount,
+            memo: None,
+        }
+    }
+
+    pub fn memo(mut self, memo: Memo) -> Recipient {
+        self.memo = Some(memo);
+        self
+    }
+}
+
+impl AddressParam for Recipient {
+    fn addresses(&self) -> Vec<&Address> {
+        vec![&self.address]
+    }
+}
+
+/// A `SendManyRequest` builds the params of `z_sendmany`, for `Client::z_sendmany_operation`, which checks them as `zcashd` would before the call is made.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SendManyRequest {
+    from: Address,
+    recipients: Vec<Recipient>,
+    minconf: Option<u32>,
+    fee: Option<ZecAmount>,
+}
+
+impl SendManyRequest {
+    /// Begin a request to send from `from`, a transparent or shielded address of the wallet.
+    pub fn new(from: Address) -> SendManyRequest {
+        SendManyRequest {
+            from,
+            recipients: Vec::new(),
+            minconf: None,
+            fee: None,
+        }
+    }
+
+    /// Send `amount` to `address`.
+    pub fn to(self, address: Address, amount: ZecAmount) -> SendManyRequest {
+        self.recipient(Recipient::new(address, amount))
+    }
+
+    /// Send `amount` to the shielded `address`, with `memo`.
+    pub fn to_with_memo(
+        self,
+        address: Address,
+        amount: ZecAmount,
+        memo: Memo,
+    ) -> SendManyRequest {
+        self.recipient(Recipient::new(address, amount).memo(memo))
+    }
+
+    pub fn recipient(mut self, recipient: Recipient) -> SendManyRequest {
+        self.recipients.push(recipient);
+        self
+    }
+
+    /// Only spend funds with at least `minconf` confirmations, rather than 1.
+    pub fn minconf(mut self, minconf: u32) -> SendManyRequest {
+        self.minconf = Some(minconf);
+        self
+    }
+
+    /// Pay `fee` rather than `zcashd`'s default fee.
+    pub fn fee(mut self, fee: ZecAmount) -> SendManyRequest {
+        self.fee = Some(fee);
+        self
+    }
+
+    pub fn from_address(&self) -> &Address {
+        &self.from
+    }
+
+    pub fn recipients(&self) -> &[Recipient] {
+        &self.recipients
+    }
+
+    /// Check the request for the mistakes `zcashd` would reject it for: no recipients, a recipient listed twice, a negative amount or fee, or a memo for a transparent address.
+    pub fn validate(&self) -> Result<(), SendManyError> {
+        if self.recipients.is_empty() {
+            return Err(SendManyError::NoRecipients);
+        }
+        for (i, recipient) in self.recipients.iter().enumerate() {
+            let address = &recipient.address;
+            if self.recipients[..i].iter().any(|r| &r.address == address) {
+                return Err(SendManyError::DuplicateAddress(address.clone()));
+            }
+            if recipient.amount.is_negative() {
+                return Err(SendManyError::NegativeAmount(address.clone()));
+            }
+            if recipient.memo.is_some() && address.is_transparent() {
+                return Err(SendManyError::MemoToTransparent(address.clone()));
+            }
+        }
+        match self.fee {
+            Some(fee) if fee.is_negative() => Err(SendManyError::NegativeFee),
+            _ => Ok(()),
+        }
+    }
+
+    /// The positional params of `z_sendmany`. A `minconf` of 1, `zcashd`'s default, is given along with a fee, since the fee follows it.
+    pub(crate) fn into_params(
+        self,
+    ) -> (Address, Vec<Recipient>, Option<u32>, Option<ZecAmount>) {
+        let minconf = self.minconf.or_else(|| self.fee.map(|_| 1));
+        (self.from, self.recipients, minconf, self.fee)
+    }
+}
@@ -0,0 +1,68 @@
+//! Helpers shared by the integration tests which send real HTTP requests.
+
+use std::io::{BufRead as _, BufReader, Read as _, Write as _};
+use std::net::TcpListener;
+use std::time::Duration;
+
+/// Accept one connection per body, replying to each request with its body, as JSON with status 200, after `delay`. Returns the server's base URL, and a handle which yields each request's head (request line and headers).
+pub fn serve<B: Into<String>>(
+    bodies: Vec<B>,
+    delay: Duration,
+) -> (String, std::thread::JoinHandle<Vec<String>>) {
+    let responses = bodies
+        .into_iter()
+        .map(|body| http_response("200 OK", "application/json", &body.into()))
+        .collect();
+    serve_raw(responses, delay)
+}
+
+/// A complete HTTP response with `status` (e.g. `"401 Unauthorized"`), `content_type` and `body`.
+pub fn http_response(status: &str, content_type: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {}\r\nConnection: close\r\nContent-Type: {}\r\nContent-Length: {}\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    )
+}
+
+/// Like `serve`, but replying with each of `responses` as it is, status line and headers included.
+pub fn serve_raw(
+    responses: Vec<String>,
+    delay: Duration,
+) -> (String, std::thread::JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let handle = std::thread::spawn(move || {
+        responses
+            .into_iter()
+            .map(|response| {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut head = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line == "\r\n" {
+                        break;
+                    }
+                    let lower = line.to_ascii_lowercase();
+                    if let Some(length) = lower.strip_prefix("content-length:")
+                    {
+                        content_length = length.trim().parse().unwrap();
+                    }
+                    head.push_str(&line);
+                }
+                let mut request_body = vec![0; content_length];
+                reader.read_exact(&mut request_body).unwrap();
+                std::thread::sleep(delay);
+                let mut stream = reader.into_inner();
+                let _ = stream.write_all(response.as_bytes());
+                head
+            })
+            .collect()
+    });
+    (format!("http://{}", addr), handle)
+}
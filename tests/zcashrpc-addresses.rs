@@ -0,0 +1,104 @@
+//! Parse and display addresses of each kind, and check that a `Client` which
+//! knows its node's network rejects addresses for another network before
+//! sending the request.
+
+use zcashrpc::{Address, Network};
+
+const MAINNET_P2PKH: &str = "t1Hsc1LR8yKnbbe3twRp88p6vFfC5t7DLbs";
+const MAINNET_P2SH: &str = "t3Vz22vK5z2LcKEdg16Yv4FFneEL1zg9ojd";
+const TESTNET_P2PKH: &str = "tmRagwRsMPqAuBtLQtuDWQzW8sYj9cNFo3t";
+const REGTEST_SAPLING: &str = "zregtestsapling1400xhmu78l75llxe953zxn7qwk0gaqer96hhn6y8pchjsfnv2a6rt8z6tnht4jhdu86r2vynhp9";
+
+fn round_trip(encoded: &str) -> Address {
+    let address: Address = encoded.parse().unwrap();
+    assert_eq!(address.to_string(), encoded);
+    address
+}
+
+#[test]
+fn transparent_addresses() {
+    let p2pkh = round_trip(MAINNET_P2PKH);
+    assert!(matches!(p2pkh, Address::P2pkh { .. }));
+    assert_eq!(p2pkh.network(), Network::Main);
+
+    let p2sh = round_trip(MAINNET_P2SH);
+    assert!(matches!(p2sh, Address::P2sh { .. }));
+    assert_eq!(p2sh.network(), Network::Main);
+
+    let testnet = round_trip(TESTNET_P2PKH);
+    assert_eq!(testnet.network(), Network::Test);
+    assert!(testnet.is_valid_for(Network::Test));
+    assert!(testnet.is_valid_for(Network::Regtest));
+    assert!(!testnet.is_valid_for(Network::Main));
+}
+
+#[test]
+fn shielded_addresses() {
+    let sapling = round_trip(REGTEST_SAPLING);
+    assert!(matches!(sapling, Address::Sapling { .. }));
+    assert!(sapling.is_valid_for(Network::Regtest));
+    assert!(!sapling.is_valid_for(Network::Test));
+
+    let sprout = Address::Sprout {
+        network: Network::Main,
+        bytes: [7; 64],
+    };
+    assert!(sprout.to_string().starts_with("zc"));
+    assert_eq!(round_trip(&sprout.to_string()), sprout);
+
+    let unified = Address::Unified {
+        network: Network::Test,
+        bytes: vec![7; 48],
+    };
+    assert!(unified.to_string().starts_with("utest1"));
+    assert_eq!(round_trip(&unified.to_string()), unified);
+}
+
+#[test]
+fn invalid_addresses() {
+    let mut corrupted = String::from(MAINNET_P2PKH);
+    corrupted.pop();
+    corrupted.push('t');
+    assert!(corrupted.parse::<Address>().is_err());
+    assert!(REGTEST_SAPLING[..60].parse::<Address>().is_err());
+    assert!("".parse::<Address>().is_err());
+
+    let bech32m = REGTEST_SAPLING.replace("zregtestsapling", "uregtest");
+    assert!(bech32m.parse::<Address>().is_err());
+}
+
+#[test]
+fn serde_uses_the_encoding() {
+    let json = format!("\"{}\"", REGTEST_SAPLING);
+    let address: Address = serde_json::from_str(&json).unwrap();
+    assert_eq!(serde_json::to_string(&address).unwrap(), json);
+}
+
+#[tokio::test]
+async fn wrong_network_is_rejected() {
+    use zcashrpc::error::InvalidRequest;
+
+    let mut client = zcashrpc::Client::new(
+        String::from("127.0.0.1:1"),
+        String::from("user:pass"),
+    )
+    .with_network(Network::Main);
+    let err = client
+        .z_getbalance(REGTEST_SAPLING.parse().unwrap(), None)
+        .await
+        .unwrap_err();
+    match err.into_inner() {
+        zcashrpc::Error::InvalidRequest(InvalidRequest::WrongNetwork {
+            method,
+            param,
+            node,
+            address,
+        }) => {
+            assert_eq!(method, "z_getbalance");
+            assert_eq!(param, "address");
+            assert_eq!(node, Network::Main);
+            assert_eq!(address, Network::Regtest);
+        }
+        other => panic!("expected WrongNetwork, found {:?}", other),
+    }
+}
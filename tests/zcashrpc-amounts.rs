@@ -0,0 +1,100 @@
+//! Parse, display, convert and do arithmetic on `ZecAmount`s, and check that
+//! they survive JSON in both of the forms zcashd uses without rounding.
+
+use std::convert::TryFrom;
+use zcashrpc::amount::{COIN, MAX_MONEY};
+use zcashrpc::{Zatoshis, ZecAmount};
+
+fn zec(s: &str) -> ZecAmount {
+    s.parse().unwrap()
+}
+
+#[test]
+fn parse_and_display() {
+    assert_eq!(zec("1.5").to_zatoshis(), Zatoshis(150_000_000));
+    assert_eq!(zec("-0.00000001").to_zatoshis(), Zatoshis(-1));
+    assert_eq!(zec(".1").to_zatoshis(), Zatoshis(10_000_000));
+    assert_eq!(zec("2.000000000").to_zatoshis(), Zatoshis(2 * COIN));
+    assert_eq!(zec("1.5").to_string(), "1.50000000");
+    assert_eq!(zec("-0.00000001").to_string(), "-0.00000001");
+    assert_eq!(ZecAmount::MAX.to_string(), "21000000.00000000");
+}
+
+#[test]
+fn invalid_amounts() {
+    use zcashrpc::error::ParseAmountError::*;
+
+    for (s, expected) in &[
+        ("", Malformed),
+        (".", Malformed),
+        ("1e-8", Malformed),
+        ("+1", Malformed),
+        ("0.000000001", TooPrecise),
+        ("21000000.00000001", OutOfRange),
+        ("-21000000.00000001", OutOfRange),
+        ("99999999999999999999", OutOfRange),
+    ] {
+        let err = s.parse::<ZecAmount>().unwrap_err();
+        assert_eq!(
+            std::mem::discriminant(&err),
+            std::mem::discriminant(expected),
+            "{}",
+            s
+        );
+    }
+}
+
+#[test]
+fn conversions() {
+    let amount = zec("12.34567891");
+    let zatoshis = Zatoshis::from(amount);
+    assert_eq!(ZecAmount::try_from(zatoshis).unwrap(), amount);
+    assert!(ZecAmount::try_from(Zatoshis(MAX_MONEY + 1)).is_err());
+
+    let decimal = rust_decimal::Decimal::from(amount);
+    assert_eq!(decimal.to_string(), "12.34567891");
+    assert_eq!(ZecAmount::try_from(decimal).unwrap(), amount);
+    let too_precise = rust_decimal::Decimal::new(1, 9);
+    assert!(ZecAmount::try_from(too_precise).is_err());
+}
+
+#[test]
+fn checked_arithmetic() {
+    let one = zec("1");
+    assert_eq!(one.checked_add(one), Some(zec("2")));
+    assert_eq!(one.checked_sub(zec("3")), Some(zec("-2")));
+    assert_eq!(one.checked_mul(3), Some(zec("3")));
+    assert_eq!(ZecAmount::MAX.checked_add(zec("0.00000001")), None);
+    assert_eq!(ZecAmount::MAX.checked_neg().unwrap().checked_sub(one), None);
+    assert_eq!(one.checked_mul(i64::MAX), None);
+    assert_eq!(Zatoshis(i64::MAX).checked_add(Zatoshis(1)), None);
+    assert!(zec("-1").is_negative());
+    assert_eq!(zec("-1").abs(), one);
+}
+
+#[test]
+fn json_without_rounding() {
+    for s in &[
+        "0.1",
+        "0.3",
+        "0.00000001",
+        "1.23456789",
+        "20999999.99999999",
+        "-20999999.99999999",
+    ] {
+        let from_number: ZecAmount = serde_json::from_str(s).unwrap();
+        assert_eq!(from_number, zec(s));
+        let from_string: ZecAmount =
+            serde_json::from_str(&format!("\"{}\"", s)).unwrap();
+        assert_eq!(from_string, zec(s));
+        let json = serde_json::to_string(&from_number).unwrap();
+        assert_eq!(serde_json::from_str::<ZecAmount>(&json).unwrap(), zec(s));
+    }
+    assert_eq!(serde_json::from_str::<ZecAmount>("3").unwrap(), zec("3"));
+    assert!(serde_json::from_str::<ZecAmount>("0.000000001").is_err());
+    assert!(serde_json::from_str::<ZecAmount>("21000001").is_err());
+
+    let zatoshis: Zatoshis = serde_json::from_str("-5").unwrap();
+    assert_eq!(zatoshis, Zatoshis(-5));
+    assert_eq!(serde_json::to_string(&zatoshis).unwrap(), "-5");
+}
@@ -0,0 +1,126 @@
+//! Check the `Authorization` header sent for each kind of `Auth`, including a
+//! cookie file which zcashd rewrites between requests.
+
+mod common;
+
+use base64::Engine as _;
+use common::{http_response, serve, serve_raw};
+use std::time::Duration;
+use zcashrpc::{Auth, Client};
+
+/// Responses to a client's first, second and third requests.
+const BLOCKCOUNTS: [&str; 3] = [
+    r#"{"result": 1234, "error": null, "id": 0}"#,
+    r#"{"result": 1234, "error": null, "id": 1}"#,
+    r#"{"result": 1234, "error": null, "id": 2}"#,
+];
+
+fn authorization(head: &str) -> Option<String> {
+    head.lines()
+        .find(|line| line.to_ascii_lowercase().starts_with("authorization:"))
+        .map(|line| line["authorization:".len()..].trim().to_string())
+}
+
+/// The `Authorization` header for `credentials`, as `user:password`.
+fn basic(credentials: &str) -> String {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(credentials);
+    format!("Basic {}", encoded)
+}
+
+fn hostport(base: &str) -> String {
+    base.trim_start_matches("http://").to_string()
+}
+
+#[tokio::test]
+async fn cookie_file_is_reread_when_rotated() {
+    let dir = std::env::temp_dir()
+        .join(format!("zcashrpc-auth-test-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let cookie = dir.join(".cookie");
+    std::fs::write(&cookie, "__cookie__:first").unwrap();
+
+    let (base, server) = serve(BLOCKCOUNTS.to_vec(), Duration::from_millis(0));
+    let mut client =
+        Client::with_auth(hostport(&base), Auth::CookieFile(cookie.clone()));
+    client.getblockcount().await.unwrap();
+    client.getblockcount().await.unwrap();
+    std::thread::sleep(Duration::from_millis(50));
+    std::fs::write(&cookie, "__cookie__:second\n").unwrap();
+    client.getblockcount().await.unwrap();
+
+    let heads = server.join().unwrap();
+    let first = basic("__cookie__:first");
+    let second = basic("__cookie__:second");
+    assert_eq!(authorization(&heads[0]), Some(first.clone()));
+    assert_eq!(authorization(&heads[1]), Some(first));
+    assert_eq!(authorization(&heads[2]), Some(second));
+
+    std::fs::remove_file(&cookie).unwrap();
+    match client.getblockcount().await.unwrap_err().into_inner() {
+        zcashrpc::Error::Auth(e) => {
+            assert_eq!(e.kind(), std::io::ErrorKind::NotFound)
+        }
+        other => panic!("expected an Auth error, found {:?}", other),
+    }
+    std::fs::remove_dir(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn cookie_file_is_reread_when_refused() {
+    let dir = std::env::temp_dir()
+        .join(format!("zcashrpc-auth-refused-test-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let cookie = dir.join(".cookie");
+    std::fs::write(&cookie, "__cookie__:first").unwrap();
+    let modified = std::fs::metadata(&cookie).unwrap().modified().unwrap();
+
+    let ok = |body| http_response("200 OK", "application/json", body);
+    let responses = vec![
+        ok(BLOCKCOUNTS[0]),
+        http_response("401 Unauthorized", "text/plain", ""),
+        ok(BLOCKCOUNTS[1]),
+    ];
+    let (base, server) = serve_raw(responses, Duration::from_millis(0));
+    let mut client =
+        Client::with_auth(hostport(&base), Auth::CookieFile(cookie.clone()));
+    client.getblockcount().await.unwrap();
+    // zcashd restarts with a new cookie, which looks unmodified.
+    std::fs::write(&cookie, "__cookie__:second").unwrap();
+    std::fs::File::options()
+        .write(true)
+        .open(&cookie)
+        .unwrap()
+        .set_modified(modified)
+        .unwrap();
+    client.getblockcount().await.unwrap();
+
+    let heads = server.join().unwrap();
+    assert_eq!(authorization(&heads[0]), Some(basic("__cookie__:first")));
+    assert_eq!(authorization(&heads[1]), Some(basic("__cookie__:first")));
+    assert_eq!(authorization(&heads[2]), Some(basic("__cookie__:second")));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn user_pass_and_none() {
+    let (base, server) =
+        serve(vec![BLOCKCOUNTS[0]; 2], Duration::from_millis(0));
+    let user_pass = Auth::UserPass {
+        user: String::from("alice"),
+        password: String::from("hunter2"),
+    };
+    assert_eq!(Auth::from_cookie("alice:hunter2\n"), user_pass);
+    Client::with_auth(hostport(&base), user_pass)
+        .getblockcount()
+        .await
+        .unwrap();
+    Client::with_auth(hostport(&base), Auth::None)
+        .getblockcount()
+        .await
+        .unwrap();
+
+    let heads = server.join().unwrap();
+    let expected = basic("alice:hunter2");
+    assert_eq!(authorization(&heads[0]), Some(expected));
+    assert_eq!(authorization(&heads[1]), None);
+}
@@ -0,0 +1,222 @@
+//! Follow the chain of `MockZcashd` with a `BlockStream`, as blocks are mined
+//! and reorgs replace them. Run with `cargo test --features mock`.
+
+use futures::StreamExt as _;
+use serde_json::{json, Value};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use zcashrpc::error::{ResponseError, RpcErrorCode};
+use zcashrpc::mock::MockZcashd;
+use zcashrpc::{BlockEvent, BlockHash, BlockStream, Checkpoint, Error};
+
+/// The hash of the block at `height` of the chain `fork`.
+fn hash(fork: u8, height: u32) -> String {
+    format!("{:02x}{:062x}", fork, height)
+}
+
+/// The `getblock` response for the block at `height` of the chain `fork`, whose parent is `parent`.
+fn block(fork: u8, height: u32, parent: Option<String>) -> Value {
+    json!({
+        "bits": "200f0f0f",
+        "confirmations": 1,
+        "difficulty": 1.0,
+        "finalsaplingroot": "00".repeat(32),
+        "hash": hash(fork, height),
+        "height": height,
+        "merkleroot": "00".repeat(32),
+        "nonce": "00".repeat(32),
+        "previousblockhash": parent,
+        "size": 1_617,
+        "time": 1_600_000_000u64 + u64::from(height),
+        "tx": [],
+        "version": 4,
+    })
+}
+
+/// A `MockZcashd` whose best chain is the blocks of `Chain`, which tests can mine and reorg while a stream follows it.
+#[derive(Clone)]
+struct Chain {
+    blocks: Arc<Mutex<Vec<Value>>>,
+    stale: Arc<Mutex<Vec<Value>>>,
+}
+
+impl Chain {
+    /// A chain of the blocks of fork 0 up to `tip`, served by `zcashd`.
+    fn start(zcashd: &MockZcashd, tip: u32) -> Chain {
+        let chain = Chain {
+            blocks: Arc::new(Mutex::new(Vec::new())),
+            stale: Arc::new(Mutex::new(Vec::new())),
+        };
+        chain.mine(0, tip + 1);
+        let blocks = chain.clone();
+        zcashd.handle("getblockcount", move |_| {
+            Ok(json!(blocks.blocks.lock().unwrap().len() - 1))
+        });
+        let blocks = chain.clone();
+        zcashd.handle("getbestblockhash", move |_| {
+            Ok(blocks.blocks.lock().unwrap().last().unwrap()["hash"].clone())
+        });
+        let blocks = chain.clone();
+        zcashd.handle("getblock", move |params| blocks.getblock(params));
+        chain
+    }
+
+    fn getblock(&self, params: &[Value]) -> Result<Value, ResponseError> {
+        let blocks = self.blocks.lock().unwrap();
+        let stale = self.stale.lock().unwrap();
+        let wanted = params[0].as_str().unwrap();
+        let found = match wanted.parse::<usize>() {
+            Ok(height) => blocks.get(height),
+            Err(_) => blocks
+                .iter()
+                .chain(stale.iter())
+                .find(|b| b["hash"] == wanted),
+        };
+        found.cloned().ok_or_else(|| ResponseError {
+            code: RpcErrorCode::InvalidAddressOrKey,
+            message: String::from("Block not found"),
+        })
+    }
+
+    /// Mine `count` blocks of the chain `fork` on the tip.
+    fn mine(&self, fork: u8, count: u32) {
+        let mut blocks = self.blocks.lock().unwrap();
+        for _ in 0..count {
+            let parent = blocks
+                .last()
+                .map(|b| b["hash"].as_str().unwrap().to_string());
+            let height = blocks.len() as u32;
+            blocks.push(block(fork, height, parent));
+        }
+    }
+
+    /// Disconnect the blocks above `height`, keeping them so they can still be fetched by hash, as `zcashd` does.
+    fn disconnect_above(&self, height: u32) {
+        let mut blocks = self.blocks.lock().unwrap();
+        let disconnected = blocks.split_off(height as usize + 1);
+        self.stale.lock().unwrap().extend(disconnected);
+    }
+}
+
+fn checkpoint(fork: u8, height: u32) -> Checkpoint {
+    Checkpoint {
+        height,
+        hash: hash(fork, height).parse::<BlockHash>().unwrap(),
+    }
+}
+
+async fn next_block(stream: &mut BlockStream) -> Checkpoint {
+    match stream.next().await.unwrap().unwrap() {
+        BlockEvent::Block(block) => Checkpoint {
+            height: block.height,
+            hash: block.hash,
+        },
+        event => panic!("unexpected event: {:?}", event),
+    }
+}
+
+/// The checkpoints of the blocks of a `BlockEvent::Reorg`.
+async fn next_reorg(
+    stream: &mut BlockStream,
+) -> (Vec<Checkpoint>, Vec<Checkpoint>) {
+    match stream.next().await.unwrap().unwrap() {
+        BlockEvent::Reorg {
+            disconnected,
+            connected,
+        } => {
+            let connected = connected
+                .iter()
+                .map(|block| Checkpoint {
+                    height: block.height,
+                    hash: block.hash,
+                })
+                .collect();
+            (disconnected, connected)
+        }
+        event => panic!("unexpected event: {:?}", event),
+    }
+}
+
+#[tokio::test]
+async fn follows_the_tip() {
+    let zcashd = MockZcashd::start().unwrap();
+    let chain = Chain::start(&zcashd, 2);
+    let mut stream = zcashd
+        .client()
+        .block_stream(1)
+        .interval(Duration::from_millis(10));
+    assert_eq!(stream.checkpoint(), None);
+    assert_eq!(next_block(&mut stream).await, checkpoint(0, 1));
+    assert_eq!(next_block(&mut stream).await, checkpoint(0, 2));
+    assert_eq!(stream.checkpoint(), Some(&checkpoint(0, 2)));
+
+    let miner = chain.clone();
+    tokio::spawn(async move {
+        tokio::time::delay_for(Duration::from_millis(50)).await;
+        miner.mine(0, 1);
+    });
+    assert_eq!(next_block(&mut stream).await, checkpoint(0, 3));
+    assert!(zcashd
+        .requests()
+        .iter()
+        .any(|r| r.method == "getbestblockhash"));
+}
+
+#[tokio::test]
+async fn reorgs() {
+    let zcashd = MockZcashd::start().unwrap();
+    let chain = Chain::start(&zcashd, 3);
+    let mut stream = zcashd
+        .client()
+        .block_stream(1)
+        .interval(Duration::from_millis(10));
+    for height in 1..=3 {
+        assert_eq!(next_block(&mut stream).await, checkpoint(0, height));
+    }
+
+    // A longer chain replaces blocks 2 and 3.
+    chain.disconnect_above(1);
+    chain.mine(1, 3);
+    let (disconnected, connected) = next_reorg(&mut stream).await;
+    assert_eq!(disconnected, vec![checkpoint(0, 2), checkpoint(0, 3)]);
+    assert_eq!(
+        connected,
+        vec![checkpoint(1, 2), checkpoint(1, 3), checkpoint(1, 4)]
+    );
+
+    // A chain of the same length replaces the tip.
+    chain.disconnect_above(3);
+    chain.mine(2, 1);
+    let (disconnected, connected) = next_reorg(&mut stream).await;
+    assert_eq!(disconnected, vec![checkpoint(1, 4)]);
+    assert_eq!(connected, vec![checkpoint(2, 4)]);
+
+    // The tip is invalidated, so the node falls back to its parent.
+    chain.disconnect_above(3);
+    let (disconnected, connected) = next_reorg(&mut stream).await;
+    assert_eq!(disconnected, vec![checkpoint(2, 4)]);
+    assert!(connected.is_empty());
+
+    chain.mine(3, 1);
+    assert_eq!(next_block(&mut stream).await, checkpoint(3, 4));
+}
+
+#[tokio::test]
+async fn resumes_from_a_checkpoint() {
+    let zcashd = MockZcashd::start().unwrap();
+    let chain = Chain::start(&zcashd, 3);
+    let mut stream = zcashd.client().block_stream(0).resume(checkpoint(0, 1));
+    assert_eq!(stream.checkpoint(), Some(&checkpoint(0, 1)));
+    assert_eq!(next_block(&mut stream).await, checkpoint(0, 2));
+
+    chain.disconnect_above(0);
+    chain.mine(2, 4);
+    let mut stale = zcashd.client().block_stream(0).resume(checkpoint(1, 3));
+    let error = stale.next().await.unwrap().unwrap_err();
+    assert!(
+        matches!(error, Error::ReorgTooDeep { depth: 1 }),
+        "{}",
+        error
+    );
+    assert_eq!(stale.checkpoint(), Some(&checkpoint(1, 3)));
+}
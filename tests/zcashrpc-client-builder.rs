@@ -0,0 +1,77 @@
+//! Build `Client`s with `ClientBuilder`, and check the requests they send
+//! against a one-shot local HTTP server.
+
+mod common;
+
+use common::serve;
+use std::time::Duration;
+use zcashrpc::error::BuildError;
+use zcashrpc::Client;
+
+const BLOCKCOUNT: &str = r#"{"result": 1234, "error": null, "id": 0}"#;
+
+#[tokio::test]
+async fn path_prefix_headers_and_auth() {
+    use reqwest::header::{HeaderName, HeaderValue};
+
+    let (base, server) = serve(vec![BLOCKCOUNT], Duration::from_millis(0));
+    let mut client = Client::builder(format!("{}/zcashd/", base))
+        .auth(zcashrpc::Auth::UserPass {
+            user: String::from("user"),
+            password: String::from("pass"),
+        })
+        .header(
+            HeaderName::from_static("x-proxy-key"),
+            HeaderValue::from_static("secret"),
+        )
+        .timeout(Duration::from_secs(10))
+        .build()
+        .unwrap();
+    assert_eq!(client.getblockcount().await.unwrap(), 1234);
+
+    let head = server.join().unwrap().remove(0).to_ascii_lowercase();
+    assert!(head.starts_with("post /zcashd/ http/1.1\r\n"), "{}", head);
+    assert!(head.contains("x-proxy-key: secret\r\n"), "{}", head);
+    assert!(
+        head.contains("authorization: basic dxnlcjpwyxnz\r\n"),
+        "{}",
+        head
+    );
+}
+
+#[tokio::test]
+async fn request_timeout() {
+    let (base, _server) = serve(vec![BLOCKCOUNT], Duration::from_secs(2));
+    let mut client = Client::builder(base)
+        .timeout(Duration::from_millis(100))
+        .build()
+        .unwrap();
+    match client.getblockcount().await.unwrap_err().into_inner() {
+        zcashrpc::Error::Http(e) => assert!(e.is_timeout(), "{}", e),
+        other => panic!("expected a timeout, found {:?}", other),
+    }
+}
+
+#[test]
+fn invalid_settings() {
+    assert!(matches!(
+        Client::builder("not a url").build(),
+        Err(BuildError::InvalidUrl(_))
+    ));
+    assert!(matches!(
+        Client::builder("ftp://127.0.0.1/").build(),
+        Err(BuildError::UnsupportedScheme(scheme)) if scheme == "ftp"
+    ));
+    assert!(matches!(
+        Client::builder("https://127.0.0.1/")
+            .reqwest_client(reqwest::Client::new())
+            .connect_timeout(Duration::from_secs(1))
+            .build(),
+        Err(BuildError::ConflictsWithClient("connect_timeout"))
+    ));
+    assert!(Client::builder("https://proxy.example/zcashd/")
+        .reqwest_client(reqwest::Client::new())
+        .timeout(Duration::from_secs(1))
+        .build()
+        .is_ok());
+}
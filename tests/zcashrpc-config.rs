@@ -0,0 +1,143 @@
+//! Read `zcash.conf`s from temporary data directories, and check the
+//! connection settings they produce.
+
+use std::path::{Path, PathBuf};
+use zcashrpc::error::ConfError;
+use zcashrpc::{Auth, Network, ZcashConf};
+
+/// A fresh, empty data directory for the test `name`.
+fn datadir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "zcashrpc-config-test-{}-{}",
+        name,
+        std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn write(dir: &Path, file: &str, contents: &str) {
+    std::fs::write(dir.join(file), contents).unwrap();
+}
+
+#[test]
+fn missing_conf_is_mainnet_defaults() {
+    let dir = datadir("missing");
+    let conf = ZcashConf::load(Some(&dir), None).unwrap();
+    assert_eq!(conf.network, Network::Main);
+    assert_eq!(conf.url().unwrap(), "http://127.0.0.1:8232/");
+    assert_eq!(conf.auth(), Auth::CookieFile(dir.join(".cookie")));
+
+    let explicit = ZcashConf::load(Some(&dir), Some(Path::new("other.conf")));
+    assert!(matches!(explicit, Err(ConfError::Read { .. })));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn regtest_with_password() {
+    let dir = datadir("regtest");
+    write(
+        &dir,
+        "zcash.conf",
+        "# a regtest node\nregtest=1\nrpcuser=alice # inline comment\n\
+         rpcpassword = hunter2\nrpcport=18300\nrpcport=18400\n",
+    );
+    let conf = ZcashConf::load(Some(&dir), None).unwrap();
+    assert_eq!(conf.network, Network::Regtest);
+    assert_eq!(conf.url().unwrap(), "http://127.0.0.1:18300/");
+    assert_eq!(
+        conf.auth(),
+        Auth::UserPass {
+            user: String::from("alice"),
+            password: String::from("hunter2"),
+        }
+    );
+    let client = conf.client().unwrap();
+    assert_eq!(client.network(), Some(Network::Regtest));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn testnet_cookie_and_rpcbind() {
+    let dir = datadir("testnet");
+    write(&dir, "node.conf", "testnet=1\nrpcbind=0.0.0.0\n");
+    let conf =
+        ZcashConf::load(Some(&dir), Some(Path::new("node.conf"))).unwrap();
+    assert_eq!(conf.network, Network::Test);
+    assert_eq!(conf.url().unwrap(), "http://127.0.0.1:18232/");
+    assert_eq!(
+        conf.auth(),
+        Auth::CookieFile(dir.join("testnet3").join(".cookie"))
+    );
+
+    for (rpcbind, url) in &[
+        ("10.0.0.2", "http://10.0.0.2:18232/"),
+        ("10.0.0.2:9000", "http://10.0.0.2:9000/"),
+        ("::", "http://[::1]:18232/"),
+        ("[fe80::1]:9000", "http://[fe80::1]:9000/"),
+    ] {
+        let conf = ZcashConf {
+            rpcbind: Some(rpcbind.to_string()),
+            ..conf.clone()
+        };
+        assert_eq!(conf.url().unwrap(), *url);
+    }
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn includeconf() {
+    let dir = datadir("include");
+    write(&dir, "zcash.conf", "rpcuser=main\nincludeconf=extra.conf\n");
+    write(
+        &dir,
+        "extra.conf",
+        "rpcuser=extra\nrpcpassword=included\ntestnet=0\nincludeconf=more.conf\n",
+    );
+    let conf = ZcashConf::load(Some(&dir), None).unwrap();
+    assert_eq!(conf.network, Network::Main);
+    assert_eq!(conf.rpcuser.as_deref(), Some("main"));
+    assert_eq!(conf.rpcpassword.as_deref(), Some("included"));
+
+    write(&dir, "zcash.conf", "includeconf=absent.conf\n");
+    assert!(matches!(
+        ZcashConf::load(Some(&dir), None),
+        Err(ConfError::Read { .. })
+    ));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn flags_are_interpreted_like_zcashd() {
+    let network = |contents| {
+        ZcashConf::parse(contents, PathBuf::from("/"))
+            .unwrap()
+            .network
+    };
+    assert_eq!(network("testnet=1\n"), Network::Test);
+    assert_eq!(network("testnet=\n"), Network::Test);
+    assert_eq!(network("testnet=2yes\n"), Network::Test);
+    assert_eq!(network("testnet=-1\n"), Network::Test);
+    // `atoi` reads no number from these, so zcashd takes them as 0.
+    assert_eq!(network("testnet=true\n"), Network::Main);
+    assert_eq!(network("regtest=yes\n"), Network::Main);
+    assert_eq!(network("regtest=00\n"), Network::Main);
+}
+
+#[test]
+fn invalid_confs() {
+    let parse = |contents| ZcashConf::parse(contents, PathBuf::from("/"));
+    assert!(matches!(
+        parse("testnet=1\nregtest=1\n"),
+        Err(ConfError::ConflictingNetworks)
+    ));
+    assert!(matches!(
+        parse("rpcport=8232\nnonsense\n"),
+        Err(ConfError::Malformed { line: 2 })
+    ));
+    assert!(matches!(
+        parse("rpcport=huge\n"),
+        Err(ConfError::InvalidValue { key: "rpcport", .. })
+    ));
+}
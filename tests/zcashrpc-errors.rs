@@ -0,0 +1,174 @@
+//! Check that `zcashd`'s error codes are decoded into `RpcErrorCode`s, and the
+//! `Error` predicates which branch on them.
+
+mod common;
+
+use common::{http_response, serve, serve_raw};
+use std::time::Duration;
+use zcashrpc::{Client, Error, RpcErrorCode};
+
+/// The response to a client's `id`th request, failing with `code`.
+fn error_response(id: u64, code: i64, message: &str) -> String {
+    format!(
+        r#"{{"result": null, "error": {{"code": {}, "message": "{}"}}, "id": {}}}"#,
+        code, message, id
+    )
+}
+
+#[test]
+fn codes_round_trip() {
+    let known = [
+        (-6, RpcErrorCode::WalletInsufficientFunds),
+        (-5, RpcErrorCode::InvalidAddressOrKey),
+        (-26, RpcErrorCode::VerifyRejected),
+        (-28, RpcErrorCode::InWarmup),
+        (-32601, RpcErrorCode::MethodNotFound),
+    ];
+    for &(code, expected) in &known {
+        assert_eq!(RpcErrorCode::from(code), expected);
+        assert_eq!(i64::from(expected), code);
+    }
+    assert_eq!(RpcErrorCode::from(-1000), RpcErrorCode::Unknown(-1000));
+    assert_eq!(i64::from(RpcErrorCode::Unknown(-1000)), -1000);
+
+    let code: RpcErrorCode = serde_json::from_str("-13").unwrap();
+    assert_eq!(code, RpcErrorCode::WalletUnlockNeeded);
+    assert_eq!(serde_json::to_string(&code).unwrap(), "-13");
+}
+
+#[tokio::test]
+async fn predicates() {
+    let (base, _server) = serve(
+        vec![
+            error_response(0, -6, "Insufficient funds"),
+            error_response(1, -1000, "Something new"),
+        ],
+        Duration::from_millis(0),
+    );
+    let mut client = Client::builder(base).build().unwrap();
+
+    let error = client.getblockcount().await.unwrap_err();
+    assert_eq!(error.method(), Some("getblockcount"));
+    assert_eq!(error.request_id(), Some(0));
+    assert!(error.is_insufficient_funds());
+    assert!(!error.is_in_warmup());
+    assert_eq!(
+        error.rpc_code(),
+        Some(RpcErrorCode::WalletInsufficientFunds)
+    );
+
+    let error = client.getblockcount().await.unwrap_err();
+    assert!(!error.is_insufficient_funds());
+    assert_eq!(error.rpc_code(), Some(RpcErrorCode::Unknown(-1000)));
+}
+
+/// The message of `error` followed by those of its sources, as `anyhow` reports it with `{:#}`.
+fn report(error: &(dyn std::error::Error + 'static)) -> String {
+    let mut messages = vec![error.to_string()];
+    let mut source = error.source();
+    while let Some(error) = source {
+        messages.push(error.to_string());
+        source = error.source();
+    }
+    messages.join(": ")
+}
+
+#[tokio::test]
+async fn messages_and_sources() {
+    let (base, _server) = serve(
+        vec![error_response(0, -6, "Insufficient funds"), "<html>".into()],
+        Duration::from_millis(0),
+    );
+    let mut client = Client::builder(base).build().unwrap();
+
+    let error = client.getblockcount().await.unwrap_err();
+    assert_eq!(error.to_string(), "getblockcount request 0 failed");
+    assert_eq!(
+        report(&error),
+        "getblockcount request 0 failed: zcashd error -6 (WalletInsufficientFunds): Insufficient funds"
+    );
+
+    // A malformed response chains down to the serde_json error, whose message is only reported once.
+    let error = client.getbestblockhash().await.unwrap_err();
+    let mut chain = Vec::new();
+    let mut source: Option<&(dyn std::error::Error + 'static)> = Some(&error);
+    while let Some(error) = source {
+        chain.push(error);
+        source = error.source();
+    }
+    let reason = chain.last().unwrap();
+    assert!(reason.is::<serde_json::Error>());
+    assert_eq!(
+        report(&error),
+        format!(
+            "getbestblockhash request 1 failed: malformed JSON response: {}",
+            reason
+        )
+    );
+
+    let boxed: Box<dyn std::error::Error + Send + Sync> = Box::new(error);
+    assert!(boxed.downcast_ref::<zcashrpc::Error>().is_some());
+}
+
+#[tokio::test]
+async fn http_statuses() {
+    let responses = vec![
+        http_response("401 Unauthorized", "text/html", ""),
+        http_response("403 Forbidden", "text/html", ""),
+        http_response(
+            "503 Service Unavailable",
+            "text/html",
+            "Work queue depth exceeded",
+        ),
+        http_response(
+            "502 Bad Gateway",
+            "text/html",
+            "<html>Bad Gateway</html>",
+        ),
+        http_response("200 OK", "application/json", ""),
+        http_response("500 Internal Server Error", "text/plain", "oops"),
+        http_response(
+            "500 Internal Server Error",
+            "application/json",
+            &error_response(6, -8, "Block height out of range"),
+        ),
+        http_response(
+            "404 Not Found",
+            "application/json",
+            &error_response(7, -32601, "Method not found"),
+        ),
+    ];
+    let (base, _server) = serve_raw(responses, Duration::from_millis(0));
+    let mut client = Client::builder(base).build().unwrap();
+    let mut next = || {
+        let response = client.getblockcount();
+        async { response.await.unwrap_err().into_inner() }
+    };
+
+    for &expected in &[401, 403] {
+        match next().await {
+            Error::Unauthorized { status } => assert_eq!(status, expected),
+            other => panic!("expected Unauthorized, found {:?}", other),
+        }
+    }
+    assert!(matches!(next().await, Error::WorkQueueExceeded));
+    for &(expected, body) in
+        &[(502, "<html>Bad Gateway</html>"), (200, ""), (500, "oops")]
+    {
+        match next().await {
+            Error::UnexpectedBody {
+                status,
+                body: found,
+            } => {
+                assert_eq!(status, expected);
+                assert_eq!(found, body);
+            }
+            other => panic!("expected UnexpectedBody, found {:?}", other),
+        }
+    }
+    assert_eq!(
+        next().await.rpc_code(),
+        Some(RpcErrorCode::InvalidParameter)
+    );
+    assert!(next().await.is_method_not_found());
+}
@@ -0,0 +1,104 @@
+//! Interpret memos as ZIP 302 specifies, and read them from the
+//! `z_viewtransaction` and `z_listreceivedbyaddress` fixtures.
+
+use zcashrpc::error::MemoError;
+use zcashrpc::memo::MEMO_SIZE;
+use zcashrpc::{Memo, MemoContent};
+
+/// The hex `zcashd` sends for a memo of `bytes`.
+fn padded_hex(bytes: &[u8]) -> String {
+    let mut padded = bytes.to_vec();
+    padded.resize(MEMO_SIZE, 0);
+    hex::encode(padded)
+}
+
+#[test]
+fn text() {
+    let hex = padded_hex("Grüße ✉".as_bytes());
+    let memo: Memo = hex.parse().unwrap();
+    assert_eq!(memo.content(), MemoContent::Text("Grüße ✉".to_string()));
+    assert_eq!(memo.text().unwrap(), "Grüße ✉");
+    assert_eq!(memo.to_string(), hex);
+    assert_eq!(memo, Memo::from_text("Grüße ✉").unwrap());
+
+    let zeros: Memo = padded_hex(&[]).parse().unwrap();
+    assert_eq!(zeros.text().unwrap(), "");
+    assert!(!zeros.is_empty());
+
+    let invalid = Memo::from_bytes(&[0xC3, 0x28]).unwrap();
+    assert_eq!(
+        invalid.content(),
+        MemoContent::InvalidText(vec![0xC3, 0x28])
+    );
+    assert_eq!(invalid.text(), None);
+}
+
+#[test]
+fn empty_marker() {
+    let memo: Memo = padded_hex(&[0xF6]).parse().unwrap();
+    assert!(memo.is_empty());
+    assert_eq!(memo, Memo::empty());
+    assert_eq!(memo.text(), None);
+
+    let future: Memo = padded_hex(&[0xF6, 0x01]).parse().unwrap();
+    assert!(!future.is_empty());
+    assert!(matches!(future.content(), MemoContent::Reserved(_)));
+}
+
+#[test]
+fn arbitrary_data() {
+    let memo = Memo::arbitrary(&[1, 2, 3]).unwrap();
+    assert_eq!(&memo.to_bytes()[..4], &[0xFF, 1, 2, 3]);
+    match memo.content() {
+        MemoContent::Arbitrary(data) => {
+            assert_eq!(data.len(), MEMO_SIZE - 1);
+            assert_eq!(&data[..3], &[1, 2, 3]);
+        }
+        other => panic!("unexpected content: {:?}", other),
+    }
+    assert!(Memo::arbitrary(&[0; MEMO_SIZE - 1]).is_ok());
+    assert!(Memo::arbitrary(&[0; MEMO_SIZE]).is_err());
+
+    let reserved: Memo = padded_hex(&[0xF5, 9]).parse().unwrap();
+    assert_eq!(
+        reserved.content(),
+        MemoContent::Reserved(reserved.to_bytes().to_vec())
+    );
+}
+
+#[test]
+fn hex_serde() {
+    let memo = Memo::from_text("hi").unwrap();
+    let json = serde_json::to_value(&memo).unwrap();
+    assert_eq!(json, serde_json::json!(padded_hex(b"hi")));
+    assert_eq!(serde_json::from_value::<Memo>(json).unwrap(), memo);
+    assert_eq!("6869".parse::<Memo>().unwrap(), memo);
+
+    assert!(matches!(
+        "zz".parse::<Memo>(),
+        Err(MemoError::InvalidHex(_))
+    ));
+    assert!(matches!(
+        "00".repeat(MEMO_SIZE + 1).parse::<Memo>(),
+        Err(MemoError::TooLong { len: 513 })
+    ));
+}
+
+#[test]
+fn memos_in_responses() {
+    use zcashrpc::client::subcomponents::z_listreceivedbyaddress::ZListreceivedbyaddressResponse;
+    use zcashrpc::client::subcomponents::z_viewtransaction::ZViewtransactionResponse;
+
+    let json = include_str!("../json_data/ZViewTransactionResponse.json");
+    let tx: ZViewtransactionResponse = serde_json::from_str(json).unwrap();
+    for output in &tx.outputs {
+        assert_eq!(output.memo.text(), output.memo_str);
+    }
+    assert!(tx.outputs[1].memo.is_empty());
+
+    let json = include_str!("../json_data/ZListReceivedByAddressResponse.json");
+    let received: ZListreceivedbyaddressResponse =
+        serde_json::from_str(json).unwrap();
+    assert_eq!(received[0].memo.text().unwrap(), "Thanks for the coffee");
+    assert!(received[1].memo.is_empty());
+}
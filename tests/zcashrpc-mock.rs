@@ -0,0 +1,265 @@
+//! Exercise the whole `Client` against `MockZcashd`: envelopes and ids,
+//! authentication, error responses, batches and retries. Run with
+//! `cargo test --features mock`.
+
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+use zcashrpc::error::{ResponseError, RpcErrorCode};
+use zcashrpc::mock::{MockRequest, MockZcashd};
+use zcashrpc::{
+    Address, Auth, BlockHash, Client, Error, Network, RetryPolicy, ZecAmount,
+};
+
+/// A block hash which encodes `height`, so that responses can be told apart.
+fn hash(height: u64) -> String {
+    format!("{:064x}", height)
+}
+
+fn getblockhash(params: &[Value]) -> Result<Value, ResponseError> {
+    match params.first().and_then(Value::as_u64) {
+        Some(height) if height <= 100 => Ok(json!(hash(height))),
+        _ => Err(ResponseError {
+            code: RpcErrorCode::InvalidParameter,
+            message: "Block height out of range".to_string(),
+        }),
+    }
+}
+
+#[tokio::test]
+async fn fixtures_and_handlers() {
+    let zcashd = MockZcashd::start().unwrap();
+    zcashd.fixture("getblockcount", json!(100));
+    zcashd
+        .fixture_file(
+            "getblockchaininfo",
+            concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/json_data/GetBlockChainInfoResponse.json"
+            ),
+        )
+        .unwrap();
+    zcashd.handle("getblockhash", getblockhash);
+
+    let mut client = zcashd.client();
+    assert_eq!(client.getblockcount().await.unwrap(), 100);
+    assert_eq!(client.verify_network().await.unwrap(), Network::Regtest);
+    let found = client.getblockhash(7).await.unwrap();
+    assert_eq!(found, hash(7).parse::<BlockHash>().unwrap());
+    assert_eq!(
+        zcashd.requests(),
+        vec![
+            MockRequest {
+                method: "getblockcount".to_string(),
+                params: vec![],
+            },
+            MockRequest {
+                method: "getblockchaininfo".to_string(),
+                params: vec![],
+            },
+            MockRequest {
+                method: "getblockhash".to_string(),
+                params: vec![json!(7)],
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn authenticates_like_zcashd() {
+    let zcashd =
+        MockZcashd::start_with_credentials("alice", "hunter2").unwrap();
+    zcashd.fixture("getblockcount", json!(100));
+    assert_eq!(
+        zcashd.auth(),
+        Auth::UserPass {
+            user: "alice".to_string(),
+            password: "hunter2".to_string(),
+        }
+    );
+    assert_eq!(zcashd.client().getblockcount().await.unwrap(), 100);
+
+    let wrong = Auth::UserPass {
+        user: "alice".to_string(),
+        password: "hunter3".to_string(),
+    };
+    for auth in [wrong, Auth::None].iter().cloned() {
+        let mut client =
+            Client::builder(zcashd.url()).auth(auth).build().unwrap();
+        match client.getblockcount().await.unwrap_err().into_inner() {
+            Error::Unauthorized { status } => assert_eq!(status, 401),
+            other => panic!("expected Unauthorized, found {:?}", other),
+        }
+    }
+    // Unauthenticated requests aren't handled, so they aren't recorded.
+    assert_eq!(zcashd.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn error_responses() {
+    let zcashd = MockZcashd::start().unwrap();
+    zcashd.handle("getblockhash", getblockhash);
+    zcashd.fail(
+        "getbestblockhash",
+        RpcErrorCode::InWarmup,
+        "Loading block index...",
+    );
+    let mut client = zcashd.client();
+
+    let error = client.getblockhash(101).await.unwrap_err();
+    assert_eq!(error.rpc_code(), Some(RpcErrorCode::InvalidParameter));
+    assert_eq!(error.request_id(), Some(0));
+    assert!(client.getbestblockhash().await.unwrap_err().is_in_warmup());
+    assert!(client
+        .getblockcount()
+        .await
+        .unwrap_err()
+        .is_method_not_found());
+}
+
+#[tokio::test]
+async fn null_results() {
+    let zcashd = MockZcashd::start().unwrap();
+    zcashd.fixture("ping", Value::Null);
+    zcashd.fixture("keypoolrefill", Value::Null);
+    let mut client = zcashd.client();
+    client.ping().await.unwrap();
+    client.keypoolrefill(Some(100)).await.unwrap();
+    assert_eq!(zcashd.requests()[1].params, vec![json!(100)]);
+}
+
+#[tokio::test]
+async fn coinbase_transactions() {
+    let zcashd = MockZcashd::start().unwrap();
+    let json = include_str!("../json_data/GetBlockTxsResponse.json");
+    let block: Value = serde_json::from_str(json).unwrap();
+    let mut coinbase = block["tx"][0].clone();
+    zcashd.fixture("getblock", block);
+    coinbase.as_object_mut().unwrap().extend(
+        json!({
+            "hex": "0400008085202f89010000",
+            "blockhash": hash(311),
+            "confirmations": 1,
+            "time": 1_608_574_523u64,
+            "blocktime": 1_608_574_523u64,
+        })
+        .as_object()
+        .unwrap()
+        .clone(),
+    );
+    zcashd.fixture("getrawtransaction", coinbase);
+
+    let mut client = zcashd.client();
+    let block = client.getblock_txs("311".to_string()).await.unwrap();
+    assert_eq!(block.height, 311);
+    assert_eq!(zcashd.requests()[0].params, vec![json!("311"), json!(2)]);
+    let (coinbase, spend) = (&block.tx[0], &block.tx[1]);
+    assert_eq!(
+        coinbase.vin[0].coinbase.as_ref().unwrap().0,
+        [2, 0x37, 1, 1, 1]
+    );
+    assert_eq!(coinbase.vin[0].txid, None);
+    assert!(coinbase.vin[0].script_sig.is_none());
+    assert_eq!(coinbase.confirmations, None);
+    assert!(spend.vin[0].coinbase.is_none());
+    assert_eq!(spend.vin[0].vout, Some(0));
+    assert_eq!(spend.vin[0].txid, Some(coinbase.txid));
+
+    let tx = client
+        .getrawtransaction_verbose(coinbase.txid)
+        .await
+        .unwrap();
+    assert!(tx.vin[0].coinbase.is_some());
+    assert_eq!(tx.confirmations, Some(1));
+}
+
+#[tokio::test]
+async fn mempool_transactions() {
+    let zcashd = MockZcashd::start().unwrap();
+    zcashd
+        .fixture_file(
+            "getrawtransaction",
+            concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/json_data/GetRawTransactionVerboseMempoolResponse.json"
+            ),
+        )
+        .unwrap();
+
+    let mut client = zcashd.client();
+    let txid =
+        "5e4f0b6fce6b0d8a32b0e1a6c3e4d5f2b1a0c9d8e7f6a5b4c3d2e1f0a9b8c7d6";
+    let tx = client
+        .getrawtransaction_verbose(txid.parse().unwrap())
+        .await
+        .unwrap();
+    assert_eq!(tx.txid.to_string(), txid);
+    assert_eq!(tx.confirmations, None);
+    assert_eq!(tx.time, None);
+    assert!(tx.blockhash.is_none());
+}
+
+#[tokio::test]
+async fn batches() {
+    let zcashd = MockZcashd::start().unwrap();
+    zcashd.handle("getblockhash", getblockhash);
+    let mut client = zcashd.client();
+
+    let mut batch = client.batch::<BlockHash>();
+    batch.getblockhash(1).getblockhash(101).getblockhash(2);
+    let results = batch.send().await.unwrap();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap(), &hash(1).parse().unwrap());
+    assert!(matches!(
+        results[1].as_ref().unwrap_err().rpc_code(),
+        Some(RpcErrorCode::InvalidParameter)
+    ));
+    assert_eq!(results[2].as_ref().unwrap(), &hash(2).parse().unwrap());
+
+    // The client's next id follows the batch's.
+    let error = client.getblockhash(101).await.unwrap_err();
+    assert_eq!(error.request_id(), Some(3));
+}
+
+#[tokio::test]
+async fn batch_rejected_client_side() {
+    let zcashd = MockZcashd::start().unwrap();
+    let mut client = zcashd.client();
+    let mainnet: Address =
+        "t1Hsc1LR8yKnbbe3twRp88p6vFfC5t7DLbs".parse().unwrap();
+
+    let mut batch = client.batch::<ZecAmount>();
+    batch
+        .z_getbalance(mainnet.clone(), None)
+        .z_getbalance(mainnet, None);
+    let results = batch.send().await.unwrap();
+    assert_eq!(results.len(), 2);
+    for result in results {
+        assert!(matches!(
+            result.unwrap_err().into_inner(),
+            Error::InvalidRequest(_)
+        ));
+    }
+    assert!(zcashd.requests().is_empty());
+}
+
+#[tokio::test]
+async fn retries_warmup() {
+    let zcashd = MockZcashd::start().unwrap();
+    let calls = AtomicU32::new(0);
+    zcashd.handle("getblockcount", move |_| {
+        if calls.fetch_add(1, Ordering::SeqCst) < 2 {
+            Err(ResponseError {
+                code: RpcErrorCode::InWarmup,
+                message: "Loading block index...".to_string(),
+            })
+        } else {
+            Ok(json!(100))
+        }
+    });
+    let policy = RetryPolicy::new()
+        .backoff(Duration::from_millis(1), Duration::from_millis(1));
+    let mut client = zcashd.client().with_retry(policy);
+    assert_eq!(client.getblockcount().await.unwrap(), 100);
+    assert_eq!(zcashd.requests().len(), 3);
+}
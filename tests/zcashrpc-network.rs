@@ -0,0 +1,73 @@
+//! Check `Client::verify_network` against a node reporting the regtest chain,
+//! and the per-network defaults.
+
+mod common;
+
+use common::serve;
+use std::time::Duration;
+use zcashrpc::{Client, Network};
+
+/// A `getblockchaininfo` response from a regtest node, to a client's first request.
+fn regtest_blockchaininfo() -> String {
+    format!(
+        r#"{{"result": {}, "error": null, "id": 0}}"#,
+        include_str!("../json_data/GetBlockChainInfoResponse.json")
+    )
+}
+
+fn client(base: &str, network: Option<Network>) -> Client {
+    let builder = Client::builder(base);
+    match network {
+        Some(network) => builder.network(network),
+        None => builder,
+    }
+    .build()
+    .unwrap()
+}
+
+#[tokio::test]
+async fn matching_or_unset_network() {
+    let (base, _server) = serve(
+        vec![regtest_blockchaininfo(), regtest_blockchaininfo()],
+        Duration::from_millis(0),
+    );
+
+    let mut configured = client(&base, Some(Network::Regtest));
+    assert_eq!(configured.verify_network().await.unwrap(), Network::Regtest);
+
+    let mut unset = client(&base, None);
+    assert_eq!(unset.verify_network().await.unwrap(), Network::Regtest);
+    assert_eq!(unset.network(), Some(Network::Regtest));
+}
+
+#[tokio::test]
+async fn mismatched_network_refuses_requests() {
+    let (base, server) =
+        serve(vec![regtest_blockchaininfo()], Duration::from_millis(0));
+    let mut client = client(&base, Some(Network::Main));
+
+    let mismatch = |err: zcashrpc::Error| match err.into_inner() {
+        zcashrpc::Error::NetworkMismatch { configured, node } => {
+            assert_eq!(configured, Network::Main);
+            assert_eq!(node, Network::Regtest);
+        }
+        other => panic!("expected NetworkMismatch, found {:?}", other),
+    };
+    mismatch(client.verify_network().await.unwrap_err());
+    // The server has stopped listening, so only a refusal can produce this.
+    mismatch(client.getblockcount().await.unwrap_err());
+    assert_eq!(server.join().unwrap().len(), 1);
+}
+
+#[test]
+fn defaults() {
+    assert_eq!(Network::Main.default_rpc_port(), 8232);
+    assert_eq!(Network::Test.default_rpc_port(), 18232);
+    assert_eq!(Network::Main.datadir_subdirectory(), None);
+    assert_eq!(Network::Test.datadir_subdirectory(), Some("testnet3"));
+    assert_eq!(Network::Regtest.datadir_subdirectory(), Some("regtest"));
+    for network in &[Network::Main, Network::Test, Network::Regtest] {
+        assert_eq!(network.to_string().parse::<Network>().unwrap(), *network);
+    }
+    assert!("testnet".parse::<Network>().is_err());
+}
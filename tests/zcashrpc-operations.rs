@@ -0,0 +1,180 @@
+//! Wait on the asynchronous operations `z_sendmany` and friends start, with
+//! `MockZcashd` playing a node whose operations take a few polls to finish.
+//! Run with `cargo test --features mock`.
+
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use zcashrpc::error::{OperationFailure, ResponseError, RpcErrorCode};
+use zcashrpc::mock::MockZcashd;
+use zcashrpc::operation::OperationState;
+use zcashrpc::{Address, Error, OperationId, SendManyRequest, TxId};
+
+const REGTEST_SAPLING: &str = "zregtestsapling1400xhmu78l75llxe953zxn7qwk0gaqer96hhn6y8pchjsfnv2a6rt8z6tnht4jhdu86r2vynhp9";
+const OPID: &str = "opid-6e581ee5-4e90-4e70-8961-f95d8d28748c";
+const TXID: &str =
+    "4f9b3dd24a2ff1cdb4fba94c2ac4ac6e5a8c3a7e1ab9e0f3f09f4e3e1d5c3a9b";
+
+/// The status of `OPID` in `state`, with `outcome` merged in once it has finished.
+fn status(state: &str, outcome: Value) -> Value {
+    let mut status = json!({
+        "id": OPID,
+        "status": state,
+        "creation_time": 1_600_000_000u64,
+        "method": "z_sendmany",
+        "params": {"fromaddress": REGTEST_SAPLING, "minconf": 1, "fee": 0.0001},
+    });
+    if let Value::Object(outcome) = outcome {
+        status.as_object_mut().unwrap().extend(outcome);
+    }
+    status
+}
+
+/// Have `zcashd` start `OPID` for `z_sendmany`, report it as executing for the first `polls` calls to `z_getoperationstatus`, and then report it finished with `outcome`.
+fn start(
+    zcashd: &MockZcashd,
+    polls: u32,
+    finished: &'static str,
+    outcome: Value,
+) {
+    zcashd.fixture("z_sendmany", json!(OPID));
+    let seen = Arc::new(AtomicU32::new(0));
+    let done = status(finished, outcome);
+    let result = done.clone();
+    zcashd.handle("z_getoperationstatus", move |_| {
+        if seen.fetch_add(1, Ordering::SeqCst) < polls {
+            Ok(json!([status("executing", Value::Null)]))
+        } else {
+            Ok(json!([done.clone()]))
+        }
+    });
+    zcashd.fixture("z_getoperationresult", json!([result]));
+}
+
+#[tokio::test]
+async fn resolves_to_the_txid() {
+    let zcashd = MockZcashd::start().unwrap();
+    let outcome = json!({"result": {"txid": TXID}, "execution_secs": 2.5});
+    start(&zcashd, 2, "success", outcome);
+
+    let mut client = zcashd.client();
+    let from: Address = REGTEST_SAPLING.parse().unwrap();
+    let request =
+        SendManyRequest::new(from.clone()).to(from, "0.5".parse().unwrap());
+    let operation = client.z_sendmany_operation(request).await.unwrap();
+    assert_eq!(operation.id(), &OperationId(OPID.to_string()));
+    let txid = operation
+        .interval(Duration::from_millis(10))
+        .wait()
+        .await
+        .unwrap();
+    assert_eq!(txid, TXID.parse::<TxId>().unwrap());
+
+    let methods: Vec<String> =
+        zcashd.requests().into_iter().map(|r| r.method).collect();
+    assert_eq!(
+        methods,
+        vec![
+            "z_sendmany",
+            "z_getoperationstatus",
+            "z_getoperationstatus",
+            "z_getoperationstatus",
+            "z_getoperationresult",
+        ]
+    );
+    assert_eq!(zcashd.requests()[1].params, vec![json!([OPID])]);
+}
+
+#[tokio::test]
+async fn reports_why_it_failed() {
+    let zcashd = MockZcashd::start().unwrap();
+    let outcome = json!({"error": {
+        "code": -6,
+        "message": "Insufficient funds, have 0.10, need 0.50001",
+    }});
+    start(&zcashd, 0, "failed", outcome);
+
+    let client = zcashd.client();
+    let error = client
+        .operation(OperationId(OPID.to_string()))
+        .interval(Duration::from_millis(10))
+        .wait()
+        .await
+        .unwrap_err();
+    assert!(error.is_insufficient_funds());
+    match error {
+        Error::Operation {
+            opid,
+            failure: OperationFailure::Failed(ResponseError { code, .. }),
+        } => {
+            assert_eq!(opid.0, OPID);
+            assert_eq!(code, RpcErrorCode::WalletInsufficientFunds);
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+
+    start(&zcashd, 0, "cancelled", Value::Null);
+    let error = client
+        .operation(OperationId(OPID.to_string()))
+        .wait()
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        Error::Operation {
+            failure: OperationFailure::Cancelled,
+            ..
+        }
+    ));
+}
+
+#[tokio::test]
+async fn times_out() {
+    let zcashd = MockZcashd::start().unwrap();
+    start(&zcashd, u32::MAX, "success", Value::Null);
+
+    let error = zcashd
+        .client()
+        .operation(OperationId(OPID.to_string()))
+        .interval(Duration::from_millis(10))
+        .timeout(Duration::from_millis(50))
+        .wait()
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        Error::Operation {
+            failure: OperationFailure::TimedOut {
+                state: OperationState::Executing
+            },
+            ..
+        }
+    ));
+    assert!(zcashd
+        .requests()
+        .iter()
+        .all(|r| r.method == "z_getoperationstatus"));
+}
+
+#[tokio::test]
+async fn unknown_operations() {
+    let zcashd = MockZcashd::start().unwrap();
+    zcashd.fixture("z_getoperationstatus", json!([]));
+
+    let mut operation = zcashd
+        .client()
+        .operation(OperationId("opid-forgotten".to_string()));
+    let error = operation.status().await.unwrap_err();
+    assert!(matches!(
+        error,
+        Error::Operation {
+            failure: OperationFailure::Unknown,
+            ..
+        }
+    ));
+    assert_eq!(
+        error.to_string(),
+        "operation opid-forgotten unknown to the node"
+    );
+}
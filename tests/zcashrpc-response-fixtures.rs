@@ -0,0 +1,385 @@
+//! Deserialize zcashd responses into the generated response types, checking
+//! every field is found under its wire name, the shape-based enums pick the
+//! right variant, and the types survive a round trip through JSON.
+//!
+//! Only `GenerateResponse.json`, `GetBlockChainInfoResponse.json`,
+//! `GetInfoResponse.json` and `ZGetNewAddressResponse.json` were recorded from
+//! a regtest zcashd. The other fixtures are written by hand after zcashd's
+//! help text and RPC code, with made-up hashes and txids, so they can't catch
+//! a field zcashd sends differently than those describe. They should be
+//! replaced with recordings, e.g. from the cassette of a `RecordingTransport`.
+//! The generated modules which have no fixture yet are listed in `UNCOVERED`.
+
+macro_rules! round_trip {
+    ($test:ident, $fixture:literal, $module:ident :: $response:ident) => {
+        #[test]
+        fn $test() {
+            use zcashrpc::client::subcomponents::$module::$response;
+
+            let json = include_str!(concat!("../json_data/", $fixture));
+            let response: $response = serde_json::from_str(json).unwrap();
+            let value = serde_json::to_value(&response).unwrap();
+            let response: $response =
+                serde_json::from_value(value.clone()).unwrap();
+            assert_eq!(serde_json::to_value(&response).unwrap(), value);
+        }
+    };
+    ($test:ident, $fixture:literal, $module:ident :: $response:ident :: $variant:ident) => {
+        #[test]
+        fn $test() {
+            use zcashrpc::client::subcomponents::$module::$response;
+
+            let json = include_str!(concat!("../json_data/", $fixture));
+            let response: $response = serde_json::from_str(json).unwrap();
+            assert!(matches!(response, $response::$variant(_)));
+
+            let value = serde_json::to_value(&response).unwrap();
+            let response: $response = serde_json::from_value(value).unwrap();
+            assert!(matches!(response, $response::$variant(_)));
+        }
+    };
+}
+
+round_trip!(
+    generate,
+    "GenerateResponse.json",
+    generate::GenerateResponse
+);
+round_trip!(getinfo, "GetInfoResponse.json", getinfo::GetinfoResponse);
+round_trip!(
+    z_getnewaddress,
+    "ZGetNewAddressResponse.json",
+    z_getnewaddress::ZGetnewaddressResponse
+);
+round_trip!(
+    getblock_hex,
+    "GetBlockHexResponse.json",
+    getblock::GetblockResponse::Regular
+);
+round_trip!(
+    getblock_verbose,
+    "GetBlockVerboseResponse.json",
+    getblock::GetblockResponse::Verbose
+);
+round_trip!(
+    getblock_txs,
+    "GetBlockTxsResponse.json",
+    getblock::GetblockResponse::VeryVerbose
+);
+round_trip!(
+    getblockheader_verbose,
+    "GetBlockHeaderVerboseResponse.json",
+    getblockheader::GetblockheaderResponse::Verbose
+);
+round_trip!(
+    getrawmempool,
+    "GetRawMempoolResponse.json",
+    getrawmempool::GetrawmempoolResponse::Regular
+);
+round_trip!(
+    getrawmempool_verbose,
+    "GetRawMempoolVerboseResponse.json",
+    getrawmempool::GetrawmempoolResponse::Verbose
+);
+round_trip!(
+    getaddressdeltas,
+    "GetAddressDeltasResponse.json",
+    getaddressdeltas::GetaddressdeltasResponse::Regular
+);
+round_trip!(
+    getaddressdeltas_chaininfo,
+    "GetAddressDeltasChainInfoResponse.json",
+    getaddressdeltas::GetaddressdeltasResponse::Verbose
+);
+round_trip!(
+    getaddressutxos,
+    "GetAddressUtxosResponse.json",
+    getaddressutxos::GetaddressutxosResponse::Regular
+);
+round_trip!(
+    getaddressutxos_chaininfo,
+    "GetAddressUtxosChainInfoResponse.json",
+    getaddressutxos::GetaddressutxosResponse::Verbose
+);
+round_trip!(
+    getrawtransaction_verbose,
+    "GetRawTransactionVerboseResponse.json",
+    getrawtransaction::GetrawtransactionResponse::Verbose
+);
+round_trip!(
+    getrawtransaction_mempool,
+    "GetRawTransactionVerboseMempoolResponse.json",
+    getrawtransaction::GetrawtransactionResponse::Verbose
+);
+round_trip!(
+    createmultisig,
+    "CreateMultisigResponse.json",
+    createmultisig::CreatemultisigResponse
+);
+round_trip!(
+    decoderawtransaction,
+    "DecodeRawTransactionResponse.json",
+    decoderawtransaction::DecoderawtransactionResponse
+);
+round_trip!(
+    decoderawtransaction_nonstandard,
+    "DecodeRawTransactionNonstandardResponse.json",
+    decoderawtransaction::DecoderawtransactionResponse
+);
+round_trip!(
+    decodescript,
+    "DecodeScriptResponse.json",
+    decodescript::DecodescriptResponse
+);
+round_trip!(
+    getblocksubsidy,
+    "GetBlockSubsidyResponse.json",
+    getblocksubsidy::GetblocksubsidyResponse
+);
+round_trip!(
+    getblockchaininfo,
+    "GetBlockChainInfoResponse.json",
+    getblockchaininfo::GetblockchaininfoResponse
+);
+round_trip!(
+    gettransaction,
+    "GetTransactionResponse.json",
+    gettransaction::GettransactionResponse
+);
+round_trip!(
+    gettxout,
+    "GetTxOutResponse.json",
+    gettxout::GettxoutResponse
+);
+round_trip!(
+    listreceivedbyaccount,
+    "ListReceivedByAccountResponse.json",
+    listreceivedbyaccount::ListreceivedbyaccountResponse
+);
+round_trip!(
+    listreceivedbyaddress,
+    "ListReceivedByAddressResponse.json",
+    listreceivedbyaddress::ListreceivedbyaddressResponse
+);
+round_trip!(
+    signrawtransaction,
+    "SignRawTransactionResponse.json",
+    signrawtransaction::SignrawtransactionResponse
+);
+round_trip!(
+    validateaddress,
+    "ValidateAddressResponse.json",
+    validateaddress::ValidateaddressResponse
+);
+round_trip!(
+    z_gettotalbalance,
+    "ZGetTotalBalanceResponse.json",
+    z_gettotalbalance::ZGettotalbalanceResponse
+);
+round_trip!(
+    z_gettreestate,
+    "ZGetTreeStateResponse.json",
+    z_gettreestate::ZGettreestateResponse
+);
+round_trip!(
+    z_listreceivedbyaddress,
+    "ZListReceivedByAddressResponse.json",
+    z_listreceivedbyaddress::ZListreceivedbyaddressResponse
+);
+round_trip!(
+    z_listunspent,
+    "ZListUnspentResponse.json",
+    z_listunspent::ZListunspentResponse
+);
+round_trip!(
+    z_mergetoaddress,
+    "ZMergeToAddressResponse.json",
+    z_mergetoaddress::ZMergetoaddressResponse
+);
+round_trip!(
+    z_shieldcoinbase,
+    "ZShieldCoinbaseResponse.json",
+    z_shieldcoinbase::ZShieldcoinbaseResponse
+);
+round_trip!(
+    z_viewtransaction,
+    "ZViewTransactionResponse.json",
+    z_viewtransaction::ZViewtransactionResponse
+);
+
+#[test]
+fn block_hash_byte_order() {
+    use zcashrpc::client::subcomponents::getblock::Verbose;
+
+    let json = include_str!("../json_data/GetBlockVerboseResponse.json");
+    let block: Verbose = serde_json::from_str(json).unwrap();
+    let displayed =
+        "031e6a826a8f742c7f52f5f0daf06334e4c20a4b2b6045b3052254800f595a57";
+    assert_eq!(block.hash.to_string(), displayed);
+    assert_eq!(block.hash, displayed.parse().unwrap());
+    assert_eq!(block.hash.as_bytes()[0], 0x57);
+    assert_eq!(block.hash.as_bytes()[31], 0x03);
+    assert!(displayed[2..].parse::<zcashrpc::BlockHash>().is_err());
+}
+
+#[test]
+fn outputs_without_addresses() {
+    use zcashrpc::client::subcomponents::decoderawtransaction::DecoderawtransactionResponse;
+
+    let json = include_str!(
+        "../json_data/DecodeRawTransactionNonstandardResponse.json"
+    );
+    let tx: DecoderawtransactionResponse = serde_json::from_str(json).unwrap();
+    let scripts: Vec<_> = tx.vout.iter().map(|v| &v.script_pub_key).collect();
+    assert_eq!(scripts[0].req_sigs, Some(1));
+    assert_eq!(scripts[0].addresses.as_ref().unwrap().len(), 1);
+    for script in &scripts[1..] {
+        assert_eq!(script.addresses, None);
+        assert_eq!(script.req_sigs, None);
+    }
+    assert_eq!(scripts[1].type_field, "nulldata");
+    assert_eq!(scripts[2].type_field, "nonstandard");
+}
+
+/// The generated modules which no `round_trip!` test covers yet.
+const UNCOVERED: &[&str] = &[
+    "addmultisigaddress",
+    "addnode",
+    "backupwallet",
+    "clearbanned",
+    "createrawtransaction",
+    "disconnectnode",
+    "dumpprivkey",
+    "dumpwallet",
+    "encryptwallet",
+    "estimatefee",
+    "estimatepriority",
+    "fundrawtransaction",
+    "getaccount",
+    "getaccountaddress",
+    "getaddednodeinfo",
+    "getaddressbalance",
+    "getaddressesbyaccount",
+    "getaddressmempool",
+    "getaddresstxids",
+    "getbalance",
+    "getbestblockhash",
+    "getblockcount",
+    "getblockdeltas",
+    "getblockhash",
+    "getblockhashes",
+    "getchaintips",
+    "getconnectioncount",
+    "getdeprecationinfo",
+    "getdifficulty",
+    "getexperimentalfeatures",
+    "getgenerate",
+    "getlocalsolps",
+    "getmemoryinfo",
+    "getmempoolinfo",
+    "getmininginfo",
+    "getnettotals",
+    "getnetworkhashps",
+    "getnetworkinfo",
+    "getnetworksolps",
+    "getnewaddress",
+    "getpeerinfo",
+    "getrawchangeaddress",
+    "getreceivedbyaccount",
+    "getreceivedbyaddress",
+    "getspentinfo",
+    "gettxoutproof",
+    "gettxoutsetinfo",
+    "getunconfirmedbalance",
+    "getwalletinfo",
+    "help",
+    "importaddress",
+    "importprivkey",
+    "importpubkey",
+    "importwallet",
+    "keypoolrefill",
+    "listaccounts",
+    "listaddressgroupings",
+    "listbanned",
+    "listlockunspent",
+    "listsinceblock",
+    "listtransactions",
+    "listunspent",
+    "lockunspent",
+    "move_mod",
+    "ping",
+    "prioritisetransaction",
+    "sendfrom",
+    "sendmany",
+    "sendrawtransaction",
+    "sendtoaddress",
+    "setaccount",
+    "setban",
+    "setgenerate",
+    "setlogfilter",
+    "settxfee",
+    "signmessage",
+    "stop",
+    "submitblock",
+    "verifychain",
+    "verifymessage",
+    "verifytxoutproof",
+    "z_exportkey",
+    "z_exportviewingkey",
+    "z_exportwallet",
+    "z_getbalance",
+    "z_getmigrationstatus",
+    "z_getnotescount",
+    "z_getpaymentdisclosure",
+    "z_importkey",
+    "z_importviewingkey",
+    "z_importwallet",
+    "z_listaddresses",
+    "z_listoperationids",
+    "z_sendmany",
+    "z_setmigration",
+    "z_validateaddress",
+    "z_validatepaymentdisclosure",
+    "zcbenchmark",
+    "zcrawjoinsplit",
+    "zcrawkeygen",
+    "zcrawreceive",
+    "zcsamplejoinsplit",
+];
+
+/// The modules of the generated `subcomponents.rs`, and those the `round_trip!` tests in this file cover.
+fn modules() -> (Vec<&'static str>, Vec<&'static str>) {
+    let generated = include_str!("../src/client/subcomponents.rs");
+    let modules = generated
+        .lines()
+        .filter_map(|line| line.strip_prefix("pub mod "))
+        .map(|line| line.trim_end_matches(" {"))
+        .collect();
+    // The response type of each `round_trip!`, e.g. `getblock::GetblockResponse::Regular`.
+    let tests = include_str!("zcashrpc-response-fixtures.rs");
+    let covered = tests
+        .split("\nround_trip!(")
+        .skip(1)
+        .filter_map(|invocation| {
+            let path = invocation.split(',').nth(2)?.trim();
+            path.split("::").next()
+        })
+        .collect();
+    (modules, covered)
+}
+
+#[test]
+fn every_module_is_covered_or_listed() {
+    let (modules, covered) = modules();
+    assert!(!modules.is_empty());
+    for module in &modules {
+        assert!(
+            covered.contains(module) != UNCOVERED.contains(module),
+            "{} should be covered by a round_trip! or listed in UNCOVERED, but not both",
+            module
+        );
+    }
+    for module in UNCOVERED {
+        assert!(modules.contains(module), "{} isn't generated", module);
+    }
+}
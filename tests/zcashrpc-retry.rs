@@ -0,0 +1,126 @@
+//! Check which failures a `RetryPolicy` retries, and that non-idempotent
+//! methods are only sent once unless allowed.
+
+mod common;
+
+use common::serve;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use zcashrpc::{Auth, Client, Error, HexBytes, RetryPolicy};
+
+const WARMUP: &str = r#"{"result": null, "error": {"code": -28, "message": "Loading block index..."}, "id": 0}"#;
+const BLOCKCOUNT: &str = r#"{"result": 1234, "error": null, "id": 0}"#;
+const TXID: &str = r#"{"result": "0000000000000000000000000000000000000000000000000000000000000001", "error": null, "id": 0}"#;
+
+fn quick() -> RetryPolicy {
+    RetryPolicy::new()
+        .backoff(Duration::from_millis(1), Duration::from_millis(10))
+        .jitter(false)
+}
+
+fn client(base: &str, policy: RetryPolicy) -> Client {
+    Client::builder(base).retry(policy).build().unwrap()
+}
+
+fn warmup_code(result: Result<impl std::fmt::Debug, Error>) -> i64 {
+    match result.map_err(Error::into_inner) {
+        Err(Error::Response(e)) => e.code.into(),
+        other => panic!("expected an error response, found {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn warmup_is_retried() {
+    let (base, server) =
+        serve(vec![WARMUP, WARMUP, BLOCKCOUNT], Duration::from_millis(0));
+    let mut client = client(&base, quick());
+    assert_eq!(client.getblockcount().await.unwrap(), 1234);
+    assert_eq!(server.join().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn missing_cookie_is_retried() {
+    let dir = std::env::temp_dir()
+        .join(format!("zcashrpc-retry-test-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let cookie = dir.join(".cookie");
+    std::fs::write(&cookie, "__cookie__:first").unwrap();
+
+    let (base, server) = serve(vec![BLOCKCOUNT], Duration::from_millis(0));
+    let mut client = Client::builder(&base)
+        .auth(Auth::CookieFile(cookie.clone()))
+        .retry(quick().max_attempts(50))
+        .build()
+        .unwrap();
+    // zcashd restarts, deleting its cookie and writing a new one.
+    std::fs::remove_file(&cookie).unwrap();
+    let restart = {
+        let cookie = cookie.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            std::fs::write(&cookie, "__cookie__:second").unwrap();
+        })
+    };
+    assert_eq!(client.getblockcount().await.unwrap(), 1234);
+    restart.join().unwrap();
+    assert_eq!(server.join().unwrap().len(), 1);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn attempts_are_limited() {
+    let (base, server) = serve(vec![WARMUP, WARMUP], Duration::from_millis(0));
+    let mut client = client(&base, quick().max_attempts(2));
+    assert_eq!(warmup_code(client.getblockcount().await), -28);
+    assert_eq!(server.join().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn non_idempotent_methods_are_sent_once() {
+    let transaction = || HexBytes(vec![0; 4]);
+    let (base, server) = serve(vec![WARMUP], Duration::from_millis(0));
+    let mut client = client(&base, quick());
+    let sent = client.sendrawtransaction(transaction(), None).await;
+    assert_eq!(warmup_code(sent), -28);
+    assert_eq!(server.join().unwrap().len(), 1);
+
+    let (base, server) = serve(vec![WARMUP, TXID], Duration::from_millis(0));
+    let mut client =
+        self::client(&base, quick().retry_method("sendrawtransaction"));
+    client
+        .sendrawtransaction(transaction(), None)
+        .await
+        .unwrap();
+    assert_eq!(server.join().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn classifier_decides() {
+    // Nothing listens on the port once the listener is dropped.
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    drop(listener);
+
+    let classified = Arc::new(AtomicU32::new(0));
+    let counter = classified.clone();
+    let policy = quick().max_attempts(3).classifier(move |error| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        RetryPolicy::is_transient(error)
+    });
+    match client(&base, policy)
+        .getblockcount()
+        .await
+        .map_err(Error::into_inner)
+    {
+        Err(Error::Http(e)) => assert!(e.is_connect()),
+        other => panic!("expected a connection error, found {:?}", other),
+    }
+    // The last attempt's error is returned without consulting the classifier.
+    assert_eq!(classified.load(Ordering::SeqCst), 2);
+
+    let (base, server) = serve(vec![WARMUP], Duration::from_millis(0));
+    let mut client = client(&base, quick().classifier(|_| false));
+    assert_eq!(warmup_code(client.getblockcount().await), -28);
+    assert_eq!(server.join().unwrap().len(), 1);
+}
@@ -0,0 +1,124 @@
+//! Build `z_sendmany` requests with `SendManyRequest`, checking the params
+//! `zcashd` receives and that requests it would reject are never sent. Run
+//! with `cargo test --features mock`.
+
+use serde_json::json;
+use zcashrpc::error::{InvalidRequest, MemoError, SendManyError};
+use zcashrpc::mock::MockZcashd;
+use zcashrpc::{Address, Error, Memo, Recipient, SendManyRequest, ZecAmount};
+
+const REGTEST_SAPLING: &str = "zregtestsapling1400xhmu78l75llxe953zxn7qwk0gaqer96hhn6y8pchjsfnv2a6rt8z6tnht4jhdu86r2vynhp9";
+const TESTNET_P2PKH: &str = "tmRagwRsMPqAuBtLQtuDWQzW8sYj9cNFo3t";
+const MAINNET_P2PKH: &str = "t1Hsc1LR8yKnbbe3twRp88p6vFfC5t7DLbs";
+
+fn address(encoded: &str) -> Address {
+    encoded.parse().unwrap()
+}
+
+fn zec(amount: &str) -> ZecAmount {
+    amount.parse().unwrap()
+}
+
+#[tokio::test]
+async fn sends_typed_recipients() {
+    let zcashd = MockZcashd::start().unwrap();
+    zcashd.fixture("z_sendmany", json!("opid-1"));
+
+    let thanks = format!("5468616e6b73{}", "00".repeat(506));
+    let request = SendManyRequest::new(address(TESTNET_P2PKH))
+        .to_with_memo(
+            address(REGTEST_SAPLING),
+            zec("1.5"),
+            Memo::from_text("Thanks").unwrap(),
+        )
+        .recipient(Recipient::new(address(TESTNET_P2PKH), zec("0.25")))
+        .fee(zec("0.0001"));
+    let operation =
+        zcashd.client().z_sendmany_operation(request).await.unwrap();
+    assert_eq!(operation.id().0, "opid-1");
+    assert_eq!(
+        zcashd.requests()[0].params,
+        vec![
+            json!(TESTNET_P2PKH),
+            json!([
+                {"address": REGTEST_SAPLING, "amount": 1.5, "memo": thanks},
+                {"address": TESTNET_P2PKH, "amount": 0.25},
+            ]),
+            json!(1),
+            json!(0.0001),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn rejects_before_sending() {
+    let zcashd = MockZcashd::start().unwrap();
+    zcashd.fixture("z_sendmany", json!("opid-1"));
+    let mut client = zcashd.client();
+    let from = SendManyRequest::new(address(REGTEST_SAPLING));
+    let memo = Memo::from_text("hi").unwrap();
+
+    let rejected = vec![
+        (from.clone(), "no recipients"),
+        (
+            from.clone()
+                .to(address(TESTNET_P2PKH), zec("1"))
+                .to(address(TESTNET_P2PKH), zec("2")),
+            "listed more than once",
+        ),
+        (
+            from.clone().to(address(TESTNET_P2PKH), zec("-1")),
+            "negative",
+        ),
+        (
+            from.clone()
+                .to_with_memo(address(TESTNET_P2PKH), zec("1"), memo),
+            "can't be sent a memo",
+        ),
+    ];
+    for (request, reason) in rejected {
+        let error = client.z_sendmany_operation(request).await.unwrap_err();
+        assert_eq!(error.method(), Some("z_sendmany"));
+        match error.into_inner() {
+            Error::InvalidRequest(InvalidRequest::SendMany(e)) => {
+                assert!(e.to_string().contains(reason), "{}", e)
+            }
+            other => panic!("expected a SendManyError, found {:?}", other),
+        }
+    }
+
+    let mainnet = from.to(address(MAINNET_P2PKH), zec("1"));
+    let error = client.z_sendmany_operation(mainnet).await.unwrap_err();
+    assert!(matches!(
+        error.into_inner(),
+        Error::InvalidRequest(InvalidRequest::WrongNetwork {
+            param: "amounts",
+            ..
+        })
+    ));
+    assert!(zcashd.requests().is_empty());
+}
+
+#[test]
+fn validation() {
+    let request = SendManyRequest::new(address(REGTEST_SAPLING))
+        .to(address(REGTEST_SAPLING), ZecAmount::ZERO);
+    assert!(request.validate().is_ok());
+    assert!(matches!(
+        request.fee(zec("-0.0001")).validate(),
+        Err(SendManyError::NegativeFee)
+    ));
+}
+
+#[test]
+fn memo_limit() {
+    let memo = Memo::from_bytes(&[0xab; 512]).unwrap();
+    assert_eq!(memo.to_string(), "ab".repeat(512));
+    assert!(matches!(
+        Memo::from_text(&"a".repeat(513)),
+        Err(MemoError::TooLong { len: 513 })
+    ));
+    let text = "€".repeat(171);
+    assert!(Memo::from_text(&text[..]).is_err());
+    assert!(Memo::from_text(&text[3..]).is_ok());
+}
@@ -0,0 +1,127 @@
+//! Call the shielded wallet methods against `MockZcashd`, checking their
+//! params reach the node as `zcashd` expects them and that Sprout and Sapling
+//! notes both decode. Run with `cargo test --features mock`.
+
+use serde_json::json;
+use zcashrpc::mock::MockZcashd;
+use zcashrpc::{Address, Error, Rescan, ZecAmount};
+
+const REGTEST_SAPLING: &str = "zregtestsapling1400xhmu78l75llxe953zxn7qwk0gaqer96hhn6y8pchjsfnv2a6rt8z6tnht4jhdu86r2vynhp9";
+const MAINNET_P2PKH: &str = "t1Hsc1LR8yKnbbe3twRp88p6vFfC5t7DLbs";
+
+fn fixture(name: &str) -> String {
+    format!("{}/json_data/{}", env!("CARGO_MANIFEST_DIR"), name)
+}
+
+#[tokio::test]
+async fn notes_of_both_pools() {
+    let zcashd = MockZcashd::start().unwrap();
+    zcashd
+        .fixture_file("z_listunspent", fixture("ZListUnspentResponse.json"))
+        .unwrap();
+    zcashd
+        .fixture_file(
+            "z_listreceivedbyaddress",
+            fixture("ZListReceivedByAddressResponse.json"),
+        )
+        .unwrap();
+
+    let mut client = zcashd.client();
+    let address: Address = REGTEST_SAPLING.parse().unwrap();
+    let unspent = client
+        .z_listunspent(
+            Some(1),
+            Some(9_999_999),
+            Some(false),
+            Some(vec![address.clone()]),
+        )
+        .await
+        .unwrap();
+    let (sapling, sprout) = (&unspent[0], &unspent[1]);
+    assert_eq!(sapling.address, address);
+    assert_eq!(sapling.outindex, Some(0));
+    assert_eq!(sapling.jsindex, None);
+    assert_eq!(sapling.memo.text().unwrap(), "Thanks for the coffee");
+    assert!(sprout.memo.is_empty());
+    assert_eq!((sprout.jsindex, sprout.jsoutindex), (Some(0), Some(1)));
+    assert_eq!(sprout.outindex, None);
+    assert_eq!(sprout.amount, "0.25".parse::<ZecAmount>().unwrap());
+
+    let received = client.z_listreceivedbyaddress(address, None).await.unwrap();
+    assert_eq!(received.len(), 2);
+    assert_eq!(received[0].amount_zat.0, 150_000_000);
+
+    assert_eq!(
+        zcashd.requests()[0].params,
+        vec![
+            json!(1),
+            json!(9_999_999),
+            json!(false),
+            json!([REGTEST_SAPLING])
+        ]
+    );
+}
+
+#[tokio::test]
+async fn keys() {
+    let zcashd = MockZcashd::start().unwrap();
+    zcashd.fixture("z_exportkey", json!("secret-extended-key-regtest1..."));
+    zcashd.fixture(
+        "z_importkey",
+        json!({"type": "sapling", "address": REGTEST_SAPLING}),
+    );
+
+    let mut client = zcashd.client();
+    let address: Address = REGTEST_SAPLING.parse().unwrap();
+    let key = client.z_exportkey(address.clone()).await.unwrap();
+    let imported = client
+        .z_importkey(key.clone(), Some(Rescan::WhenKeyIsNew), Some(100))
+        .await
+        .unwrap();
+    assert_eq!(imported.address, address);
+    assert_eq!(imported.type_field, "sapling");
+    assert_eq!(
+        zcashd.requests()[1].params,
+        vec![json!(key), json!("whenkeyisnew"), json!(100)]
+    );
+
+    let mainnet: Address = MAINNET_P2PKH.parse().unwrap();
+    let error = client.z_exportkey(mainnet).await.unwrap_err();
+    assert!(matches!(error.into_inner(), Error::InvalidRequest(_)));
+    assert_eq!(zcashd.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn balances() {
+    let zcashd = MockZcashd::start().unwrap();
+    zcashd
+        .fixture_file(
+            "z_gettotalbalance",
+            fixture("ZGetTotalBalanceResponse.json"),
+        )
+        .unwrap();
+    zcashd.fixture("z_getbalance", json!(1.75));
+    zcashd.fixture("z_listaddresses", json!([REGTEST_SAPLING]));
+
+    let mut client = zcashd.client();
+    let total = client.z_gettotalbalance(Some(1), Some(true)).await.unwrap();
+    assert_eq!(total.transparent, "1.25".parse::<ZecAmount>().unwrap());
+    let addresses = client.z_listaddresses(None).await.unwrap();
+    let balance = client
+        .z_getbalance(addresses[0].clone(), Some(0))
+        .await
+        .unwrap();
+    assert_eq!(balance, "1.75".parse::<ZecAmount>().unwrap());
+}
+
+#[test]
+fn rescan_values() {
+    for rescan in &[Rescan::Yes, Rescan::No, Rescan::WhenKeyIsNew] {
+        assert_eq!(rescan.to_string().parse::<Rescan>().unwrap(), *rescan);
+    }
+    assert_eq!(
+        serde_json::to_value(Rescan::WhenKeyIsNew).unwrap(),
+        json!("whenkeyisnew")
+    );
+    assert!("always".parse::<Rescan>().is_err());
+}
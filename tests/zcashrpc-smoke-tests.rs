@@ -1,9 +1,9 @@
 macro_rules! run_smoketest {
-    ($x:ident) => {
+    ($x:ident $( ( $( $arg:expr ),* ) )?) => {
         #[tokio::test]
         async fn $x() {
-            let _response = zcashrpc::client::utils::make_client(true)
-                .$x()
+            let _response = zcashrpc::client::utils::make_client()
+                .$x($($( $arg ),*)?)
                 .await
                 .unwrap();
         }
@@ -11,4 +11,34 @@ macro_rules! run_smoketest {
 }
 
 run_smoketest!(getblockchaininfo);
-run_smoketest!(z_getnewaddress);
+run_smoketest!(z_getnewaddress(None));
+
+#[tokio::test]
+async fn batch_getblockchaininfo() {
+    use zcashrpc::client::subcomponents::getblockchaininfo::GetblockchaininfoResponse;
+
+    let mut client = zcashrpc::client::utils::make_client();
+    let mut batch = client.batch::<GetblockchaininfoResponse>();
+    batch.getblockchaininfo().getblockchaininfo();
+    let responses = batch.send().await.unwrap();
+    assert_eq!(responses.len(), 2);
+    for response in responses {
+        response.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn getblock_verbose() {
+    let mut client = zcashrpc::client::utils::make_client();
+    let hash = client.getbestblockhash().await.unwrap();
+    let block = client.getblock_verbose(hash.to_string()).await.unwrap();
+    assert_eq!(block.hash, hash);
+    assert_eq!(block.nextblockhash, None);
+}
+
+#[tokio::test]
+async fn verify_network() {
+    let mut client = zcashrpc::client::utils::make_client();
+    let network = client.verify_network().await.unwrap();
+    assert_eq!(client.network(), Some(network));
+}
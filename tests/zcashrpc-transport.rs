@@ -0,0 +1,88 @@
+//! Record a session through `RecordingTransport`, then play it back with
+//! `ReplayTransport` once the server is gone.
+
+mod common;
+
+use common::serve;
+use std::sync::Arc;
+use std::time::Duration;
+use zcashrpc::error::TransportError;
+use zcashrpc::{
+    BlockHash, Client, Error, RecordingTransport, ReplayTransport, RpcErrorCode,
+};
+
+const RESPONSES: [&str; 3] = [
+    r#"{"result": 1234, "error": null, "id": 0}"#,
+    r#"[{"result": "0000000000000000000000000000000000000000000000000000000000000001", "error": null, "id": 1},
+        {"result": null, "error": {"code": -8, "message": "Block height out of range"}, "id": 2}]"#,
+    r#"{"result": null, "error": {"code": -28, "message": "Loading block index..."}, "id": 3}"#,
+];
+
+/// Make the calls of the recorded session, in either order.
+async fn session(client: &mut Client, reversed: bool) {
+    let mut steps = vec![0, 1, 2];
+    if reversed {
+        steps.reverse();
+    }
+    for step in steps {
+        match step {
+            0 => assert_eq!(client.getblockcount().await.unwrap(), 1234),
+            1 => {
+                let mut batch = client.batch::<BlockHash>();
+                batch.getblockhash(1).getblockhash(100_000);
+                let results = batch.send().await.unwrap();
+                assert_eq!(
+                    results[0].as_ref().unwrap(),
+                    &format!("{:064x}", 1).parse().unwrap()
+                );
+                assert_eq!(
+                    results[1].as_ref().unwrap_err().rpc_code(),
+                    Some(RpcErrorCode::InvalidParameter)
+                );
+            }
+            _ => assert!(client
+                .getbestblockhash()
+                .await
+                .unwrap_err()
+                .is_in_warmup()),
+        }
+    }
+}
+
+#[tokio::test]
+async fn record_then_replay() {
+    let cassette = std::env::temp_dir().join(format!(
+        "zcashrpc-transport-test-{}.jsonl",
+        std::process::id()
+    ));
+
+    let (base, server) = serve(RESPONSES.to_vec(), Duration::from_millis(0));
+    let http = Client::builder(base).build_transport().unwrap();
+    let recording = RecordingTransport::create(http, &cassette).unwrap();
+    session(&mut Client::from_transport(recording), false).await;
+    assert_eq!(server.join().unwrap().len(), 3);
+
+    let lines = std::fs::read_to_string(&cassette).unwrap();
+    assert_eq!(lines.lines().count(), 3);
+    for line in lines.lines() {
+        let interaction: serde_json::Value =
+            serde_json::from_str(line).unwrap();
+        assert!(interaction.get("request").is_some());
+        assert!(interaction.get("response").is_some());
+    }
+
+    // Replayed in reverse, every request has a different id than the one
+    // recorded.
+    let replay = Arc::new(ReplayTransport::load(&cassette).unwrap());
+    let mut client = Client::from_transport(replay.clone());
+    session(&mut client, true).await;
+    assert_eq!(replay.remaining(), 0);
+
+    match client.getblockcount().await.unwrap_err().into_inner() {
+        Error::Transport(TransportError::NotRecorded { request }) => {
+            assert_eq!(request["method"], "getblockcount")
+        }
+        other => panic!("expected NotRecorded, found {:?}", other),
+    }
+    std::fs::remove_file(&cassette).unwrap();
+}
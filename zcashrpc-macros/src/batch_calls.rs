@@ -0,0 +1,19 @@
+pub fn make_batch_call(
+    input: (proc_macro2::Ident, proc_macro2::Group),
+) -> proc_macro2::TokenStream {
+    let (call_ident, response_ident, param_stream, arg_id_stream) =
+        crate::utils::format_input("Response", input);
+    let address_checks = crate::utils::address_checks(param_stream.clone());
+    quote::quote!(
+        impl<'a>
+            Batch<
+                'a,
+                crate::client::subcomponents::#call_ident::#response_ident,
+            >
+        {
+            pub fn #call_ident(&mut self, #param_stream) -> &mut Self {
+                rpc_call!(self.#call_ident(#arg_id_stream) #address_checks)
+            }
+        }
+    )
+}
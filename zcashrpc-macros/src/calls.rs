@@ -3,12 +3,15 @@ pub fn make_call(
 ) -> proc_macro2::TokenStream {
     let (call_ident, response_ident, param_stream, arg_id_stream) =
         crate::utils::format_input("Response", input);
+    let address_checks = crate::utils::address_checks(param_stream.clone());
     quote::quote!(
         pub fn #call_ident(
             &mut self,
             #param_stream
-        ) -> impl Future<Output = ResponseResult<#response_ident>> {
-            rpc_call!(self.#call_ident(#arg_id_stream))
+        ) -> impl Future<Output = ResponseResult<
+            crate::client::subcomponents::#call_ident::#response_ident,
+        >> {
+            rpc_call!(self.#call_ident(#arg_id_stream) #address_checks)
         }
     )
     .into()
@@ -5,7 +5,7 @@ pub fn make_command(
         crate::utils::format_input("Cmd", input);
     let formatted_param_vec: proc_macro2::TokenStream =
         if !param_stream.is_empty() {
-            group_up(param_stream)
+            crate::utils::split_params(param_stream)
                 .into_iter()
                 .map(|ts| {
                     quote::quote!(
@@ -17,8 +17,11 @@ pub fn make_command(
         } else {
             param_stream
         };
-    let arg_id_vec: Vec<proc_macro2::TokenStream> =
-        arg_id_stream.into_iter().map(|tt| tt.into()).collect();
+    let arg_id_vec: Vec<proc_macro2::TokenStream> = arg_id_stream
+        .into_iter()
+        .filter(|tt| matches!(tt, proc_macro2::TokenTree::Ident(_)))
+        .map(|tt| tt.into())
+        .collect();
     quote::quote!(
         ///Macro-generated rpc method
         #[derive(Command, Debug, abscissa_core::Options)]
@@ -34,7 +37,7 @@ pub fn make_command(
             fn run(&self) {
                 abscissa_tokio::run(&crate::application::APPLICATION, async {
                     let response =
-                        zcashrpc::client::utils::make_client(true).#call_ident(
+                        zcashrpc::client::utils::make_client().#call_ident(
                             #(self.#arg_id_vec),*
                         );
                     println!("Help flag: {:?}", self.help);
@@ -45,21 +48,3 @@ pub fn make_command(
     )
     .into()
 }
-
-fn group_up(args: proc_macro2::TokenStream) -> Vec<proc_macro2::TokenStream> {
-    args.into_iter()
-        .collect::<Vec<proc_macro2::TokenTree>>()
-        .split(|tt| {
-            if let proc_macro2::TokenTree::Punct(p) = tt {
-                p.as_char() == ','
-            } else {
-                false
-            }
-        })
-        .map(|s| {
-            Vec::from(s)
-                .into_iter()
-                .collect::<proc_macro2::TokenStream>()
-        })
-        .collect()
-}
@@ -1,6 +1,8 @@
+mod batch_calls;
 mod calls;
 mod cli_commands;
 mod responses;
+mod spec;
 mod utils;
 
 use proc_macro::TokenStream;
@@ -10,6 +12,11 @@ pub fn declare_rpc_client_methods(input: TokenStream) -> TokenStream {
     utils::make_code(input.into(), calls::make_call).into()
 }
 
+#[proc_macro]
+pub fn declare_rpc_batch_methods(input: TokenStream) -> TokenStream {
+    utils::make_code(input.into(), batch_calls::make_batch_call).into()
+}
+
 #[proc_macro]
 pub fn declare_rpc_response_types(_input: TokenStream) -> TokenStream {
     responses::declare_rpc_response_types().into()
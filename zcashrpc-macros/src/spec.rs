@@ -0,0 +1,58 @@
+//! Reads the machine-readable RPC method specification, a JSON array of
+//! `{"method": "z_getnewaddress", "params": ["name: Type", ...]}` entries,
+//! into the same `(Ident, Group)` pairs produced from hand-written macro input.
+
+pub fn read_spec(
+    spec_path: proc_macro2::Literal,
+) -> Vec<(proc_macro2::Ident, proc_macro2::Group)> {
+    let relative_path = spec_path.to_string().trim_matches('"').to_string();
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .expect("CARGO_MANIFEST_DIR is not set");
+    let full_path = std::path::Path::new(&manifest_dir).join(&relative_path);
+    let spec_text = std::fs::read_to_string(&full_path)
+        .unwrap_or_else(|e| panic!("Couldn't read {}: {}", relative_path, e));
+    let spec: Vec<serde_json::Value> = serde_json::from_str(&spec_text)
+        .unwrap_or_else(|e| panic!("Invalid spec {}: {}", relative_path, e));
+    spec.iter()
+        .map(|entry| {
+            let method = entry["method"]
+                .as_str()
+                .unwrap_or_else(|| panic!("Spec entry without method"));
+            let params = entry["params"]
+                .as_array()
+                .unwrap_or_else(|| panic!("{} has no params array", method))
+                .iter()
+                .map(|param| {
+                    param.as_str().unwrap_or_else(|| {
+                        panic!("{} has a non-string param", method)
+                    })
+                })
+                .collect::<Vec<&str>>()
+                .join(", ");
+            (
+                proc_macro2::Ident::new(
+                    &under_to_camel(method),
+                    proc_macro2::Span::call_site(),
+                ),
+                proc_macro2::Group::new(
+                    proc_macro2::Delimiter::Parenthesis,
+                    params.parse().unwrap_or_else(|_| {
+                        panic!("{} has unparseable params", method)
+                    }),
+                ),
+            )
+        })
+        .collect()
+}
+
+fn under_to_camel(name: &str) -> String {
+    name.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
@@ -4,12 +4,30 @@ pub fn make_code(
         (proc_macro2::Ident, proc_macro2::Group),
     ) -> proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
+    let (calls_with_args, tracked) = match input.clone().into_iter().next() {
+        Some(proc_macro2::TokenTree::Literal(spec_path)) => {
+            let tracked = track_spec(&spec_path);
+            (crate::spec::read_spec(spec_path), tracked)
+        }
+        _ => (
+            conjoin_calls_with_args(input.into_iter(), Vec::new()),
+            proc_macro2::TokenStream::new(),
+        ),
+    };
     let code_vec: Vec<proc_macro2::TokenStream> =
-        conjoin_calls_with_args(input.into_iter(), Vec::new())
-            .into_iter()
-            .map(map_fn)
-            .collect();
-    quote::quote!(#(#code_vec)*)
+        calls_with_args.into_iter().map(map_fn).collect();
+    quote::quote!(#tracked #(#code_vec)*)
+}
+
+/// Include the spec in the output, so that Cargo rebuilds the calling crate
+/// when it changes, since it doesn't track the file `read_spec` reads. The
+/// const is named, because the output may be expanded inside an `impl`.
+fn track_spec(spec_path: &proc_macro2::Literal) -> proc_macro2::TokenStream {
+    quote::quote!(
+        #[allow(dead_code)]
+        const RPC_METHODS_SPEC: &'static str =
+            include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", #spec_path));
+    )
 }
 
 type CallsWithArgs = Vec<(proc_macro2::Ident, proc_macro2::Group)>;
@@ -45,26 +63,97 @@ fn conjoin_calls_with_args(
     }
 }
 
-enum NextIdent {
-    Arg,
-    Type,
+/// Split a parameter list like `a: u32, b: HashMap<String, u32>` on its
+/// top-level commas, ignoring commas nested inside angle brackets.
+pub fn split_params(
+    params: proc_macro2::TokenStream,
+) -> Vec<proc_macro2::TokenStream> {
+    let mut split = vec![Vec::new()];
+    let mut angle_depth = 0usize;
+    for tt in params {
+        if let proc_macro2::TokenTree::Punct(p) = &tt {
+            match p.as_char() {
+                '<' => angle_depth += 1,
+                '>' => angle_depth = angle_depth.saturating_sub(1),
+                ',' if angle_depth == 0 => {
+                    split.push(Vec::new());
+                    continue;
+                }
+                _ => (),
+            }
+        }
+        split.last_mut().unwrap().push(tt);
+    }
+    split
+        .into_iter()
+        .filter(|param| !param.is_empty())
+        .map(|param| param.into_iter().collect())
+        .collect()
 }
 
-fn strip_types(
-    mut iter: proc_macro2::token_stream::IntoIter,
-    mut vec: Vec<proc_macro2::TokenTree>,
-    next_ident: NextIdent,
+/// Reduce typed parameters to their names. Trailing `Option<T>` parameters
+/// are separated from the required ones by a `;`, so `rpc_call!` can omit
+/// them from the positional `params` array when they are `None`.
+fn strip_types(params: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let mut required = Vec::new();
+    let mut optional = Vec::new();
+    for param in split_params(params) {
+        let mut tokens = param.into_iter();
+        let name = match tokens.next() {
+            Some(proc_macro2::TokenTree::Ident(i)) => i,
+            other => panic!("Expected a parameter name, found {:?}", other),
+        };
+        match tokens.nth(1) {
+            Some(proc_macro2::TokenTree::Ident(ty)) if ty == "Option" => {
+                optional.push(name)
+            }
+            _ if !optional.is_empty() => panic!(
+                "Required parameter `{}` follows an optional parameter",
+                name
+            ),
+            _ => required.push(name),
+        }
+    }
+    if optional.is_empty() {
+        quote::quote!(#(#required),*)
+    } else {
+        quote::quote!(#(#required),* ; #(#optional),*)
+    }
+}
+
+/// The param types which hold an `Address`.
+const ADDRESS_TYPES: &[&str] = &["Address", "Recipient"];
+
+/// Name the parameters whose type holds an `Address`, as a `where` clause
+/// for `rpc_call!`, which checks them against the node's network.
+pub fn address_checks(
+    params: proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
-    match iter.next() {
-        Some(proc_macro2::TokenTree::Ident(i)) => match next_ident {
-            NextIdent::Arg => {
-                vec.push(i.into());
-                strip_types(iter, vec, NextIdent::Type)
+    fn mentions_address(tokens: proc_macro2::TokenStream) -> bool {
+        tokens.into_iter().any(|tt| match tt {
+            proc_macro2::TokenTree::Ident(i) => {
+                ADDRESS_TYPES.iter().any(|t| i == t)
+            }
+            proc_macro2::TokenTree::Group(g) => mentions_address(g.stream()),
+            _ => false,
+        })
+    }
+    let names: Vec<proc_macro2::TokenTree> = split_params(params)
+        .into_iter()
+        .filter_map(|param| {
+            let mut tokens = param.into_iter();
+            let name = tokens.next()?;
+            if mentions_address(tokens.collect()) {
+                Some(name)
+            } else {
+                None
             }
-            NextIdent::Type => strip_types(iter, vec, NextIdent::Arg),
-        },
-        Some(_) => strip_types(iter, vec, next_ident),
-        None => vec.into_iter().collect(),
+        })
+        .collect();
+    if names.is_empty() {
+        proc_macro2::TokenStream::new()
+    } else {
+        quote::quote!(where #(#names),*)
     }
 }
 
@@ -78,17 +167,20 @@ pub fn format_input(
     input: (proc_macro2::Ident, proc_macro2::Group),
 ) -> (SnakeCase, CamelCaseStruct, TypedArgs, UntypedArgs) {
     let (ident, params) = input;
-    let mut call_ident_string = ident.to_string().to_lowercase();
-    if call_ident_string.starts_with('z') {
+    let ident_string = ident.to_string();
+    let mut call_ident_string = ident_string.to_lowercase();
+    // `ZGetnewaddress` is `z_getnewaddress`, but `Zcbenchmark` is `zcbenchmark`
+    if ident_string.starts_with('Z')
+        && ident_string[1..].starts_with(|c: char| c.is_ascii_uppercase())
+    {
         call_ident_string.insert(1, '_');
     }
     let call_ident = proc_macro2::Ident::new(&call_ident_string, ident.span());
     let response_ident = proc_macro2::Ident::new(
-        &format!("{}{}", ident.to_string(), suffix),
+        &format!("{}{}", ident_string, suffix),
         ident.span(),
     );
     let param_stream = params.stream();
-    let arg_id_stream: proc_macro2::TokenStream =
-        strip_types(params.stream().into_iter(), Vec::new(), NextIdent::Arg);
+    let arg_id_stream: proc_macro2::TokenStream = strip_types(params.stream());
     (call_ident, response_ident, param_stream, arg_id_stream)
 }
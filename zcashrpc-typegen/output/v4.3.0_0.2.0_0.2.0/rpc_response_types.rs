@@ -17,8 +17,9 @@ pub mod clearbanned {
 pub mod createmultisig {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct CreatemultisigResponse {
-        pub address: String,
-        pub redeem_script: String,
+        pub address: crate::Address,
+        #[serde(rename = "redeemScript")]
+        pub redeem_script: crate::HexBytes,
     }
 }
 pub mod createrawtransaction {
@@ -27,66 +28,73 @@ pub mod createrawtransaction {
 pub mod decoderawtransaction {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct DecoderawtransactionResponse {
-        pub expiryheight: Option<rust_decimal::Decimal>,
+        pub expiryheight: Option<u32>,
         pub versiongroupid: Option<String>,
-        pub locktime: rust_decimal::Decimal,
+        pub locktime: u32,
         pub overwintered: bool,
-        pub size: rust_decimal::Decimal,
-        pub txid: String,
-        pub version: rust_decimal::Decimal,
+        pub size: u64,
+        pub txid: crate::TxId,
+        pub version: u32,
         pub vin: Vec<Vin>,
         pub vjoinsplit: Vec<Vjoinsplit>,
         pub vout: Vec<Vout>,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct ScriptPubKey {
-        pub addresses: Vec<String>,
+        pub addresses: Option<Vec<crate::Address>>,
         pub asm: String,
-        pub hex: String,
-        pub req_sigs: rust_decimal::Decimal,
+        pub hex: crate::HexBytes,
+        #[serde(rename = "reqSigs")]
+        pub req_sigs: Option<u32>,
         #[serde(rename = "type")]
         pub type_field: String,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct ScriptSig {
         pub asm: String,
-        pub hex: String,
+        pub hex: crate::HexBytes,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Vin {
-        pub script_sig: ScriptSig,
-        pub sequence: rust_decimal::Decimal,
-        pub txid: String,
-        pub vout: rust_decimal::Decimal,
+        pub coinbase: Option<crate::HexBytes>,
+        #[serde(rename = "scriptSig")]
+        pub script_sig: Option<ScriptSig>,
+        pub sequence: u32,
+        pub txid: Option<crate::TxId>,
+        pub vout: Option<u32>,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Vjoinsplit {
-        pub anchor: String,
+        pub anchor: crate::MerkleRoot,
         pub ciphertexts: Vec<String>,
         pub commitments: Vec<String>,
         pub macs: Vec<String>,
         pub nullifiers: Vec<String>,
+        #[serde(rename = "onetimePubKey")]
         pub onetime_pub_key: String,
         pub proof: String,
+        #[serde(rename = "randomSeed")]
         pub random_seed: String,
-        pub vpub_new: rust_decimal::Decimal,
-        pub vpub_old: rust_decimal::Decimal,
+        pub vpub_new: crate::ZecAmount,
+        pub vpub_old: crate::ZecAmount,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Vout {
-        pub n: rust_decimal::Decimal,
+        pub n: u32,
+        #[serde(rename = "scriptPubKey")]
         pub script_pub_key: ScriptPubKey,
-        pub value: rust_decimal::Decimal,
+        pub value: crate::ZecAmount,
     }
 }
 pub mod decodescript {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct DecodescriptResponse {
-        pub addresses: Vec<String>,
+        pub addresses: Vec<crate::Address>,
         pub asm: String,
-        pub hex: String,
-        pub p2sh: String,
-        pub req_sigs: rust_decimal::Decimal,
+        pub hex: crate::HexBytes,
+        pub p2sh: crate::Address,
+        #[serde(rename = "reqSigs")]
+        pub req_sigs: u32,
         #[serde(rename = "type")]
         pub type_field: String,
     }
@@ -106,7 +114,7 @@ pub mod encryptwallet {
     pub struct EncryptwalletResponse;
 }
 pub mod estimatefee {
-    pub type EstimatefeeResponse = rust_decimal::Decimal;
+    pub type EstimatefeeResponse = crate::ZecAmount;
 }
 pub mod estimatepriority {
     pub type EstimatepriorityResponse = rust_decimal::Decimal;
@@ -114,9 +122,9 @@ pub mod estimatepriority {
 pub mod fundrawtransaction {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct FundrawtransactionResponse {
-        pub changepos: rust_decimal::Decimal,
-        pub fee: rust_decimal::Decimal,
-        pub hex: String,
+        pub changepos: u32,
+        pub fee: crate::ZecAmount,
+        pub hex: crate::HexBytes,
     }
 }
 pub mod generate {
@@ -126,7 +134,7 @@ pub mod getaccount {
     pub type GetaccountResponse = String;
 }
 pub mod getaccountaddress {
-    pub type GetaccountaddressResponse = String;
+    pub type GetaccountaddressResponse = crate::Address;
 }
 pub mod getaddednodeinfo {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
@@ -151,54 +159,57 @@ pub mod getaddressbalance {
 }
 pub mod getaddressdeltas {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    #[serde(untagged)]
     pub enum GetaddressdeltasResponse {
         Regular(Vec<Regular>),
-        Verbose {
-            deltas: Vec<Deltas>,
-            end: End,
-            start: Start,
-        },
+        Verbose(Verbose),
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Deltas {
-        pub address: String,
-        pub height: rust_decimal::Decimal,
-        pub index: rust_decimal::Decimal,
-        pub satoshis: rust_decimal::Decimal,
-        pub txid: String,
+        pub address: crate::Address,
+        pub height: u32,
+        pub index: u32,
+        pub satoshis: crate::Zatoshis,
+        pub txid: crate::TxId,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct End {
-        pub hash: String,
-        pub height: rust_decimal::Decimal,
+        pub hash: crate::BlockHash,
+        pub height: u32,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Regular {
-        pub address: String,
-        pub height: rust_decimal::Decimal,
-        pub index: rust_decimal::Decimal,
-        pub satoshis: rust_decimal::Decimal,
-        pub txid: String,
+        pub address: crate::Address,
+        pub height: u32,
+        pub index: u32,
+        pub satoshis: crate::Zatoshis,
+        pub txid: crate::TxId,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Start {
-        pub hash: String,
-        pub height: rust_decimal::Decimal,
+        pub hash: crate::BlockHash,
+        pub height: u32,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Verbose {
+        pub deltas: Vec<Deltas>,
+        pub end: End,
+        pub start: Start,
     }
 }
 pub mod getaddressesbyaccount {
-    pub type GetaddressesbyaccountResponse = Vec<String>;
+    pub type GetaddressesbyaccountResponse = Vec<crate::Address>;
 }
 pub mod getaddressmempool {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Getaddressmempool {
-        pub address: String,
-        pub index: rust_decimal::Decimal,
+        pub address: crate::Address,
+        pub index: u32,
         pub prevout: String,
-        pub prevtxid: String,
-        pub satoshis: rust_decimal::Decimal,
-        pub timestamp: rust_decimal::Decimal,
-        pub txid: String,
+        pub prevtxid: crate::TxId,
+        pub satoshis: crate::Zatoshis,
+        pub timestamp: u64,
+        pub txid: crate::TxId,
     }
     pub type GetaddressmempoolResponse = Vec<Getaddressmempool>;
 }
@@ -207,132 +218,148 @@ pub mod getaddresstxids {
 }
 pub mod getaddressutxos {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    #[serde(untagged)]
     pub enum GetaddressutxosResponse {
         Regular(Vec<Regular>),
-        Verbose {
-            hash: String,
-            height: rust_decimal::Decimal,
-            utxos: Vec<Utxos>,
-        },
+        Verbose(Verbose),
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Regular {
-        pub address: String,
-        pub height: rust_decimal::Decimal,
-        pub output_index: rust_decimal::Decimal,
-        pub satoshis: rust_decimal::Decimal,
-        pub script: String,
-        pub txid: String,
+        pub address: crate::Address,
+        pub height: u32,
+        #[serde(rename = "outputIndex")]
+        pub output_index: u32,
+        pub satoshis: crate::Zatoshis,
+        pub script: crate::HexBytes,
+        pub txid: crate::TxId,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Utxos {
-        pub address: String,
-        pub height: rust_decimal::Decimal,
-        pub output_index: rust_decimal::Decimal,
-        pub satoshis: rust_decimal::Decimal,
-        pub script: String,
-        pub txid: String,
+        pub address: crate::Address,
+        pub height: u32,
+        #[serde(rename = "outputIndex")]
+        pub output_index: u32,
+        pub satoshis: crate::Zatoshis,
+        pub script: crate::HexBytes,
+        pub txid: crate::TxId,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Verbose {
+        pub hash: crate::BlockHash,
+        pub height: u32,
+        pub utxos: Vec<Utxos>,
     }
 }
 pub mod getbalance {
-    pub type GetbalanceResponse = rust_decimal::Decimal;
+    pub type GetbalanceResponse = crate::ZecAmount;
 }
 pub mod getbestblockhash {
-    pub type GetbestblockhashResponse = String;
+    pub type GetbestblockhashResponse = crate::BlockHash;
 }
 pub mod getblock {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    #[serde(untagged)]
     pub enum GetblockResponse {
         Regular(String),
-        Verbose {
-            bits: String,
-            confirmations: rust_decimal::Decimal,
-            difficulty: rust_decimal::Decimal,
-            finalsaplingroot: String,
-            hash: String,
-            height: rust_decimal::Decimal,
-            merkleroot: String,
-            nextblockhash: String,
-            nonce: rust_decimal::Decimal,
-            previousblockhash: String,
-            size: rust_decimal::Decimal,
-            time: rust_decimal::Decimal,
-            tx: Vec<String>,
-            version: rust_decimal::Decimal,
-        },
-        VeryVerbose {
-            bits: String,
-            confirmations: rust_decimal::Decimal,
-            difficulty: rust_decimal::Decimal,
-            finalsaplingroot: String,
-            hash: String,
-            height: rust_decimal::Decimal,
-            merkleroot: String,
-            nextblockhash: String,
-            nonce: rust_decimal::Decimal,
-            previousblockhash: String,
-            size: rust_decimal::Decimal,
-            time: rust_decimal::Decimal,
-            tx: Vec<Tx>,
-            version: rust_decimal::Decimal,
-        },
+        Verbose(Verbose),
+        VeryVerbose(VeryVerbose),
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct ScriptPubKey {
-        pub addresses: Vec<String>,
+        pub addresses: Option<Vec<crate::Address>>,
         pub asm: String,
-        pub hex: String,
-        pub req_sigs: rust_decimal::Decimal,
+        pub hex: crate::HexBytes,
+        #[serde(rename = "reqSigs")]
+        pub req_sigs: Option<u32>,
         #[serde(rename = "type")]
         pub type_field: String,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct ScriptSig {
         pub asm: String,
-        pub hex: String,
+        pub hex: crate::HexBytes,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Tx {
-        pub expiryheight: Option<rust_decimal::Decimal>,
-        pub blockhash: String,
-        pub blocktime: rust_decimal::Decimal,
-        pub confirmations: rust_decimal::Decimal,
-        pub hex: String,
-        pub in_active_chain: bool,
-        pub locktime: rust_decimal::Decimal,
-        pub size: rust_decimal::Decimal,
-        pub time: rust_decimal::Decimal,
-        pub txid: String,
-        pub version: rust_decimal::Decimal,
+        pub expiryheight: Option<u32>,
+        pub blockhash: Option<crate::BlockHash>,
+        pub blocktime: Option<u64>,
+        pub confirmations: Option<i64>,
+        pub hex: Option<crate::HexBytes>,
+        pub in_active_chain: Option<bool>,
+        pub locktime: u32,
+        pub size: u64,
+        pub time: Option<u64>,
+        pub txid: crate::TxId,
+        pub version: u32,
         pub vin: Vec<Vin>,
         pub vjoinsplit: Vec<Vjoinsplit>,
         pub vout: Vec<Vout>,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Verbose {
+        pub bits: String,
+        pub confirmations: i64,
+        pub difficulty: rust_decimal::Decimal,
+        pub finalsaplingroot: crate::MerkleRoot,
+        pub hash: crate::BlockHash,
+        pub height: u32,
+        pub merkleroot: crate::MerkleRoot,
+        pub nextblockhash: Option<crate::BlockHash>,
+        pub nonce: crate::HexBytes,
+        pub previousblockhash: Option<crate::BlockHash>,
+        pub size: u64,
+        pub time: u64,
+        pub tx: Vec<crate::TxId>,
+        pub version: u32,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct VeryVerbose {
+        pub bits: String,
+        pub confirmations: i64,
+        pub difficulty: rust_decimal::Decimal,
+        pub finalsaplingroot: crate::MerkleRoot,
+        pub hash: crate::BlockHash,
+        pub height: u32,
+        pub merkleroot: crate::MerkleRoot,
+        pub nextblockhash: Option<crate::BlockHash>,
+        pub nonce: crate::HexBytes,
+        pub previousblockhash: Option<crate::BlockHash>,
+        pub size: u64,
+        pub time: u64,
+        pub tx: Vec<Tx>,
+        pub version: u32,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Vin {
-        pub script_sig: ScriptSig,
-        pub sequence: rust_decimal::Decimal,
-        pub txid: String,
-        pub vout: rust_decimal::Decimal,
+        pub coinbase: Option<crate::HexBytes>,
+        #[serde(rename = "scriptSig")]
+        pub script_sig: Option<ScriptSig>,
+        pub sequence: u32,
+        pub txid: Option<crate::TxId>,
+        pub vout: Option<u32>,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Vjoinsplit {
-        pub anchor: String,
+        pub anchor: crate::MerkleRoot,
         pub ciphertexts: Vec<String>,
         pub commitments: Vec<String>,
         pub macs: Vec<String>,
         pub nullifiers: Vec<String>,
+        #[serde(rename = "onetimePubKey")]
         pub onetime_pub_key: String,
         pub proof: String,
+        #[serde(rename = "randomSeed")]
         pub random_seed: String,
-        pub vpub_new: rust_decimal::Decimal,
-        pub vpub_old: rust_decimal::Decimal,
+        pub vpub_new: crate::ZecAmount,
+        pub vpub_old: crate::ZecAmount,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Vout {
-        pub n: rust_decimal::Decimal,
+        pub n: u32,
+        #[serde(rename = "scriptPubKey")]
         pub script_pub_key: ScriptPubKey,
-        pub value: rust_decimal::Decimal,
+        pub value: crate::ZecAmount,
     }
 }
 pub mod getblockchaininfo {
@@ -343,155 +370,159 @@ pub mod getblockchaininfo {
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Enforce {
-        pub found: rust_decimal::Decimal,
-        pub required: rust_decimal::Decimal,
+        pub found: u32,
+        pub required: u32,
         pub status: bool,
-        pub window: rust_decimal::Decimal,
+        pub window: u32,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct GetblockchaininfoResponse {
-        pub bestblockhash: String,
-        pub blocks: rust_decimal::Decimal,
-        pub chain: String,
+        pub bestblockhash: crate::BlockHash,
+        pub blocks: u32,
+        pub chain: crate::Network,
         pub chainwork: String,
-        pub commitments: rust_decimal::Decimal,
+        pub commitments: u64,
         pub consensus: Consensus,
         pub difficulty: rust_decimal::Decimal,
-        pub estimatedheight: rust_decimal::Decimal,
-        pub headers: rust_decimal::Decimal,
-        pub initial_block_download_complete: bool,
-        pub size_on_disk: rust_decimal::Decimal,
+        pub estimatedheight: u32,
+        pub headers: u32,
+        pub initial_block_download_complete: Option<bool>,
+        pub size_on_disk: u64,
         pub softforks: Vec<Softforks>,
         pub upgrades: std::collections::HashMap<String, Upgrades>,
         pub verificationprogress: rust_decimal::Decimal,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Reject {
-        pub found: rust_decimal::Decimal,
-        pub required: rust_decimal::Decimal,
+        pub found: u32,
+        pub required: u32,
         pub status: bool,
-        pub window: rust_decimal::Decimal,
+        pub window: u32,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Softforks {
         pub enforce: Enforce,
         pub id: String,
         pub reject: Reject,
-        pub version: rust_decimal::Decimal,
+        pub version: u32,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Upgrades {
-        pub activationheight: rust_decimal::Decimal,
+        pub activationheight: u32,
         pub info: String,
         pub name: String,
         pub status: String,
     }
 }
 pub mod getblockcount {
-    pub type GetblockcountResponse = rust_decimal::Decimal;
+    pub type GetblockcountResponse = u32;
 }
 pub mod getblockdeltas {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Deltas {
-        pub index: rust_decimal::Decimal,
+        pub index: u32,
         pub inputs: Vec<Inputs>,
         pub outputs: Vec<Outputs>,
-        pub txid: String,
+        pub txid: crate::TxId,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct GetblockdeltasResponse {
         pub bits: String,
         pub chainwork: String,
-        pub confirmations: rust_decimal::Decimal,
+        pub confirmations: i64,
         pub deltas: Vec<Deltas>,
         pub difficulty: rust_decimal::Decimal,
-        pub hash: String,
-        pub height: rust_decimal::Decimal,
-        pub mediantime: rust_decimal::Decimal,
-        pub merkleroot: String,
-        pub nextblockhash: String,
+        pub hash: crate::BlockHash,
+        pub height: u32,
+        pub mediantime: u64,
+        pub merkleroot: crate::MerkleRoot,
+        pub nextblockhash: Option<crate::BlockHash>,
         pub nonce: String,
-        pub previousblockhash: String,
-        pub size: rust_decimal::Decimal,
-        pub time: rust_decimal::Decimal,
-        pub version: rust_decimal::Decimal,
+        pub previousblockhash: Option<crate::BlockHash>,
+        pub size: u64,
+        pub time: u64,
+        pub version: u32,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Inputs {
-        pub address: String,
-        pub index: rust_decimal::Decimal,
-        pub prevout: rust_decimal::Decimal,
-        pub prevtxid: String,
-        pub satoshis: rust_decimal::Decimal,
+        pub address: crate::Address,
+        pub index: u32,
+        pub prevout: u32,
+        pub prevtxid: crate::TxId,
+        pub satoshis: crate::Zatoshis,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Outputs {
-        pub address: String,
-        pub index: rust_decimal::Decimal,
-        pub satoshis: rust_decimal::Decimal,
+        pub address: crate::Address,
+        pub index: u32,
+        pub satoshis: crate::Zatoshis,
     }
 }
 pub mod getblockhash {
-    pub type GetblockhashResponse = String;
+    pub type GetblockhashResponse = crate::BlockHash;
 }
 pub mod getblockhashes {
     pub type GetblockhashesResponse = Vec<String>;
 }
 pub mod getblockheader {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    #[serde(untagged)]
     pub enum GetblockheaderResponse {
         Regular(String),
-        Verbose {
-            bits: String,
-            confirmations: rust_decimal::Decimal,
-            difficulty: rust_decimal::Decimal,
-            finalsaplingroot: String,
-            hash: String,
-            height: rust_decimal::Decimal,
-            merkleroot: String,
-            nextblockhash: String,
-            nonce: rust_decimal::Decimal,
-            previousblockhash: String,
-            time: rust_decimal::Decimal,
-            version: rust_decimal::Decimal,
-        },
+        Verbose(Verbose),
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Verbose {
+        pub bits: String,
+        pub confirmations: i64,
+        pub difficulty: rust_decimal::Decimal,
+        pub finalsaplingroot: crate::MerkleRoot,
+        pub hash: crate::BlockHash,
+        pub height: u32,
+        pub merkleroot: crate::MerkleRoot,
+        pub nextblockhash: Option<crate::BlockHash>,
+        pub nonce: crate::HexBytes,
+        pub previousblockhash: Option<crate::BlockHash>,
+        pub time: u64,
+        pub version: u32,
     }
 }
 pub mod getblocksubsidy {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Fundingstreams {
-        pub address: String,
+        pub address: crate::Address,
         pub recipient: String,
         pub specification: String,
-        pub value: rust_decimal::Decimal,
-        pub value_zat: rust_decimal::Decimal,
+        pub value: crate::ZecAmount,
+        #[serde(rename = "valueZat")]
+        pub value_zat: crate::Zatoshis,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct GetblocksubsidyResponse {
-        pub founders: rust_decimal::Decimal,
+        pub founders: crate::ZecAmount,
         pub fundingstreams: Vec<Fundingstreams>,
-        pub miner: rust_decimal::Decimal,
+        pub miner: crate::ZecAmount,
     }
 }
 pub mod getchaintips {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Getchaintips {
-        pub branchlen: rust_decimal::Decimal,
-        pub hash: String,
-        pub height: rust_decimal::Decimal,
+        pub branchlen: u32,
+        pub hash: crate::BlockHash,
+        pub height: u32,
         pub status: String,
     }
     pub type GetchaintipsResponse = Vec<Getchaintips>;
 }
 pub mod getconnectioncount {
-    pub type GetconnectioncountResponse = rust_decimal::Decimal;
+    pub type GetconnectioncountResponse = u32;
 }
 pub mod getdeprecationinfo {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct GetdeprecationinfoResponse {
-        pub deprecationheight: rust_decimal::Decimal,
+        pub deprecationheight: u32,
         pub subversion: String,
-        pub version: rust_decimal::Decimal,
+        pub version: u32,
     }
 }
 pub mod getdifficulty {
@@ -507,21 +538,21 @@ pub mod getinfo {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct GetinfoResponse {
         pub proxy: Option<String>,
-        pub balance: rust_decimal::Decimal,
-        pub blocks: rust_decimal::Decimal,
-        pub connections: rust_decimal::Decimal,
+        pub balance: crate::ZecAmount,
+        pub blocks: u32,
+        pub connections: u32,
         pub difficulty: rust_decimal::Decimal,
         pub errors: String,
-        pub keypoololdest: rust_decimal::Decimal,
-        pub keypoolsize: rust_decimal::Decimal,
-        pub paytxfee: rust_decimal::Decimal,
-        pub protocolversion: rust_decimal::Decimal,
-        pub relayfee: rust_decimal::Decimal,
+        pub keypoololdest: u64,
+        pub keypoolsize: u32,
+        pub paytxfee: crate::ZecAmount,
+        pub protocolversion: u32,
+        pub relayfee: crate::ZecAmount,
         pub testnet: bool,
-        pub timeoffset: rust_decimal::Decimal,
-        pub unlocked_until: rust_decimal::Decimal,
-        pub version: rust_decimal::Decimal,
-        pub walletversion: rust_decimal::Decimal,
+        pub timeoffset: i64,
+        pub unlocked_until: Option<u64>,
+        pub version: u32,
+        pub walletversion: u32,
     }
 }
 pub mod getlocalsolps {
@@ -534,55 +565,55 @@ pub mod getmemoryinfo {
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Locked {
-        pub chunks_free: rust_decimal::Decimal,
-        pub chunks_used: rust_decimal::Decimal,
-        pub free: rust_decimal::Decimal,
-        pub locked: rust_decimal::Decimal,
+        pub chunks_free: u64,
+        pub chunks_used: u64,
+        pub free: u64,
+        pub locked: u64,
         pub total: rust_decimal::Decimal,
-        pub used: rust_decimal::Decimal,
+        pub used: u64,
     }
 }
 pub mod getmempoolinfo {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct GetmempoolinfoResponse {
-        pub bytes: rust_decimal::Decimal,
-        pub size: rust_decimal::Decimal,
-        pub usage: rust_decimal::Decimal,
+        pub bytes: u64,
+        pub size: u64,
+        pub usage: u64,
     }
 }
 pub mod getmininginfo {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct GetmininginfoResponse {
-        pub blocks: rust_decimal::Decimal,
-        pub chain: String,
-        pub currentblocksize: rust_decimal::Decimal,
-        pub currentblocktx: rust_decimal::Decimal,
+        pub blocks: u32,
+        pub chain: crate::Network,
+        pub currentblocksize: u64,
+        pub currentblocktx: u32,
         pub difficulty: rust_decimal::Decimal,
         pub errors: String,
         pub generate: bool,
-        pub genproclimit: rust_decimal::Decimal,
+        pub genproclimit: i64,
         pub localsolps: rust_decimal::Decimal,
         pub networksolps: rust_decimal::Decimal,
-        pub pooledtx: rust_decimal::Decimal,
+        pub pooledtx: u32,
         pub testnet: bool,
     }
 }
 pub mod getnettotals {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct GetnettotalsResponse {
-        pub timemillis: rust_decimal::Decimal,
-        pub totalbytesrecv: rust_decimal::Decimal,
-        pub totalbytessent: rust_decimal::Decimal,
+        pub timemillis: u64,
+        pub totalbytesrecv: u64,
+        pub totalbytessent: u64,
         pub uploadtarget: Uploadtarget,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Uploadtarget {
-        pub bytes_left_in_cycle: rust_decimal::Decimal,
+        pub bytes_left_in_cycle: u64,
         pub serve_historical_blocks: bool,
-        pub target: rust_decimal::Decimal,
+        pub target: u64,
         pub target_reached: bool,
-        pub time_left_in_cycle: rust_decimal::Decimal,
-        pub timeframe: rust_decimal::Decimal,
+        pub time_left_in_cycle: u64,
+        pub timeframe: u64,
     }
 }
 pub mod getnetworkhashps {
@@ -591,22 +622,22 @@ pub mod getnetworkhashps {
 pub mod getnetworkinfo {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct GetnetworkinfoResponse {
-        pub connections: rust_decimal::Decimal,
+        pub connections: u32,
         pub localaddresses: Vec<Localaddresses>,
         pub localservices: String,
         pub networks: Vec<Networks>,
-        pub protocolversion: rust_decimal::Decimal,
-        pub relayfee: rust_decimal::Decimal,
+        pub protocolversion: u32,
+        pub relayfee: crate::ZecAmount,
         pub subversion: String,
-        pub timeoffset: rust_decimal::Decimal,
-        pub version: rust_decimal::Decimal,
+        pub timeoffset: i64,
+        pub version: u32,
         pub warnings: String,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Localaddresses {
         pub address: String,
-        pub port: rust_decimal::Decimal,
-        pub score: rust_decimal::Decimal,
+        pub port: u32,
+        pub score: u32,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Networks {
@@ -620,181 +651,195 @@ pub mod getnetworksolps {
     pub type GetnetworksolpsResponse = rust_decimal::Decimal;
 }
 pub mod getnewaddress {
-    pub type GetnewaddressResponse = String;
+    pub type GetnewaddressResponse = crate::Address;
 }
 pub mod getpeerinfo {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Getpeerinfo {
         pub addr: String,
         pub addrlocal: String,
-        pub banscore: rust_decimal::Decimal,
-        pub bytesrecv: rust_decimal::Decimal,
-        pub bytessent: rust_decimal::Decimal,
-        pub conntime: rust_decimal::Decimal,
-        pub id: rust_decimal::Decimal,
+        pub banscore: i64,
+        pub bytesrecv: u64,
+        pub bytessent: u64,
+        pub conntime: u64,
+        pub id: u64,
         pub inbound: bool,
         pub inflight: Vec<rust_decimal::Decimal>,
-        pub lastrecv: rust_decimal::Decimal,
-        pub lastsend: rust_decimal::Decimal,
+        pub lastrecv: u64,
+        pub lastsend: u64,
         pub pingtime: rust_decimal::Decimal,
         pub pingwait: rust_decimal::Decimal,
         pub services: String,
-        pub startingheight: rust_decimal::Decimal,
+        pub startingheight: u32,
         pub subver: String,
-        pub synced_blocks: rust_decimal::Decimal,
-        pub synced_headers: rust_decimal::Decimal,
-        pub timeoffset: rust_decimal::Decimal,
-        pub version: rust_decimal::Decimal,
+        pub synced_blocks: i64,
+        pub synced_headers: i64,
+        pub timeoffset: i64,
+        pub version: u32,
     }
     pub type GetpeerinfoResponse = Vec<Getpeerinfo>;
 }
 pub mod getrawchangeaddress {
-    pub type GetrawchangeaddressResponse = String;
+    pub type GetrawchangeaddressResponse = crate::Address;
 }
 pub mod getrawmempool {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    #[serde(untagged)]
     pub enum GetrawmempoolResponse {
         Regular(Vec<String>),
-        Verbose { transactionid: Transactionid },
+        Verbose(std::collections::HashMap<String, Verbose>),
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
-    pub struct Transactionid {
+    pub struct Verbose {
         pub currentpriority: rust_decimal::Decimal,
-        pub depends: Vec<String>,
-        pub fee: rust_decimal::Decimal,
-        pub height: rust_decimal::Decimal,
-        pub size: rust_decimal::Decimal,
+        pub depends: Vec<crate::TxId>,
+        pub fee: crate::ZecAmount,
+        pub height: u32,
+        pub size: u64,
         pub startingpriority: rust_decimal::Decimal,
-        pub time: rust_decimal::Decimal,
+        pub time: u64,
     }
 }
 pub mod getrawtransaction {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    #[serde(untagged)]
     pub enum GetrawtransactionResponse {
         Regular(String),
-        Verbose {
-            expiryheight: Option<rust_decimal::Decimal>,
-            blockhash: String,
-            blocktime: rust_decimal::Decimal,
-            confirmations: rust_decimal::Decimal,
-            hex: String,
-            in_active_chain: bool,
-            locktime: rust_decimal::Decimal,
-            size: rust_decimal::Decimal,
-            time: rust_decimal::Decimal,
-            txid: String,
-            version: rust_decimal::Decimal,
-            vin: Vec<Vin>,
-            vjoinsplit: Vec<Vjoinsplit>,
-            vout: Vec<Vout>,
-        },
+        Verbose(Verbose),
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct ScriptPubKey {
-        pub addresses: Vec<String>,
+        pub addresses: Option<Vec<crate::Address>>,
         pub asm: String,
-        pub hex: String,
-        pub req_sigs: rust_decimal::Decimal,
+        pub hex: crate::HexBytes,
+        #[serde(rename = "reqSigs")]
+        pub req_sigs: Option<u32>,
         #[serde(rename = "type")]
         pub type_field: String,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct ScriptSig {
         pub asm: String,
-        pub hex: String,
+        pub hex: crate::HexBytes,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Verbose {
+        pub expiryheight: Option<u32>,
+        pub blockhash: Option<crate::BlockHash>,
+        pub blocktime: Option<u64>,
+        pub confirmations: Option<i64>,
+        pub hex: crate::HexBytes,
+        pub in_active_chain: Option<bool>,
+        pub locktime: u32,
+        pub size: u64,
+        pub time: Option<u64>,
+        pub txid: crate::TxId,
+        pub version: u32,
+        pub vin: Vec<Vin>,
+        pub vjoinsplit: Vec<Vjoinsplit>,
+        pub vout: Vec<Vout>,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Vin {
-        pub script_sig: ScriptSig,
-        pub sequence: rust_decimal::Decimal,
-        pub txid: String,
-        pub vout: rust_decimal::Decimal,
+        pub coinbase: Option<crate::HexBytes>,
+        #[serde(rename = "scriptSig")]
+        pub script_sig: Option<ScriptSig>,
+        pub sequence: u32,
+        pub txid: Option<crate::TxId>,
+        pub vout: Option<u32>,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Vjoinsplit {
-        pub anchor: String,
+        pub anchor: crate::MerkleRoot,
         pub ciphertexts: Vec<String>,
         pub commitments: Vec<String>,
         pub macs: Vec<String>,
         pub nullifiers: Vec<String>,
+        #[serde(rename = "onetimePubKey")]
         pub onetime_pub_key: String,
         pub proof: String,
+        #[serde(rename = "randomSeed")]
         pub random_seed: String,
-        pub vpub_new: rust_decimal::Decimal,
-        pub vpub_old: rust_decimal::Decimal,
+        pub vpub_new: crate::ZecAmount,
+        pub vpub_old: crate::ZecAmount,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Vout {
-        pub n: rust_decimal::Decimal,
+        pub n: u32,
+        #[serde(rename = "scriptPubKey")]
         pub script_pub_key: ScriptPubKey,
-        pub value: rust_decimal::Decimal,
+        pub value: crate::ZecAmount,
     }
 }
 pub mod getreceivedbyaccount {
-    pub type GetreceivedbyaccountResponse = rust_decimal::Decimal;
+    pub type GetreceivedbyaccountResponse = crate::ZecAmount;
 }
 pub mod getreceivedbyaddress {
-    pub type GetreceivedbyaddressResponse = rust_decimal::Decimal;
+    pub type GetreceivedbyaddressResponse = crate::ZecAmount;
 }
 pub mod getspentinfo {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct GetspentinfoResponse {
-        pub index: rust_decimal::Decimal,
-        pub txid: String,
+        pub index: u32,
+        pub txid: crate::TxId,
     }
 }
 pub mod gettransaction {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Details {
         pub account: String,
-        pub address: String,
-        pub amount: rust_decimal::Decimal,
-        pub amount_zat: rust_decimal::Decimal,
+        pub address: crate::Address,
+        pub amount: crate::ZecAmount,
+        #[serde(rename = "amountZat")]
+        pub amount_zat: crate::Zatoshis,
         pub category: String,
-        pub vout: rust_decimal::Decimal,
+        pub vout: u32,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct GettransactionResponse {
-        pub amount: rust_decimal::Decimal,
-        pub amount_zat: rust_decimal::Decimal,
-        pub blockhash: String,
-        pub blockindex: rust_decimal::Decimal,
-        pub blocktime: rust_decimal::Decimal,
-        pub confirmations: rust_decimal::Decimal,
+        pub amount: crate::ZecAmount,
+        #[serde(rename = "amountZat")]
+        pub amount_zat: crate::Zatoshis,
+        pub blockhash: Option<crate::BlockHash>,
+        pub blockindex: u32,
+        pub blocktime: Option<u64>,
+        pub confirmations: i64,
         pub details: Vec<Details>,
-        pub hex: String,
+        pub hex: crate::HexBytes,
         pub status: String,
-        pub time: rust_decimal::Decimal,
-        pub timereceived: rust_decimal::Decimal,
-        pub txid: String,
+        pub time: u64,
+        pub timereceived: u64,
+        pub txid: crate::TxId,
         pub vjoinsplit: Vec<Vjoinsplit>,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Vjoinsplit {
-        pub anchor: String,
+        pub anchor: crate::MerkleRoot,
         pub commitments: Vec<String>,
         pub macs: Vec<String>,
         pub nullifiers: Vec<String>,
-        pub vpub_new: rust_decimal::Decimal,
-        pub vpub_old: rust_decimal::Decimal,
+        pub vpub_new: crate::ZecAmount,
+        pub vpub_old: crate::ZecAmount,
     }
 }
 pub mod gettxout {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct GettxoutResponse {
-        pub bestblock: String,
+        pub bestblock: crate::BlockHash,
         pub coinbase: bool,
-        pub confirmations: rust_decimal::Decimal,
+        pub confirmations: i64,
+        #[serde(rename = "scriptPubKey")]
         pub script_pub_key: ScriptPubKey,
-        pub value: rust_decimal::Decimal,
-        pub version: rust_decimal::Decimal,
+        pub value: crate::ZecAmount,
+        pub version: u32,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct ScriptPubKey {
-        pub addresses: Vec<String>,
+        pub addresses: Option<Vec<crate::Address>>,
         pub asm: String,
-        pub hex: String,
-        pub req_sigs: rust_decimal::Decimal,
+        pub hex: crate::HexBytes,
+        #[serde(rename = "reqSigs")]
+        pub req_sigs: Option<u32>,
         #[serde(rename = "type")]
         pub type_field: String,
     }
@@ -805,33 +850,33 @@ pub mod gettxoutproof {
 pub mod gettxoutsetinfo {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct GettxoutsetinfoResponse {
-        pub bestblock: String,
-        pub bytes_serialized: rust_decimal::Decimal,
+        pub bestblock: crate::BlockHash,
+        pub bytes_serialized: u64,
         pub hash_serialized: String,
-        pub height: rust_decimal::Decimal,
-        pub total_amount: rust_decimal::Decimal,
-        pub transactions: rust_decimal::Decimal,
-        pub txouts: rust_decimal::Decimal,
+        pub height: u32,
+        pub total_amount: crate::ZecAmount,
+        pub transactions: u64,
+        pub txouts: u64,
     }
 }
 pub mod getunconfirmedbalance {
-    pub type GetunconfirmedbalanceResponse = rust_decimal::Decimal;
+    pub type GetunconfirmedbalanceResponse = crate::ZecAmount;
 }
 pub mod getwalletinfo {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct GetwalletinfoResponse {
-        pub balance: rust_decimal::Decimal,
-        pub immature_balance: rust_decimal::Decimal,
-        pub keypoololdest: rust_decimal::Decimal,
-        pub keypoolsize: rust_decimal::Decimal,
-        pub paytxfee: rust_decimal::Decimal,
+        pub balance: crate::ZecAmount,
+        pub immature_balance: crate::ZecAmount,
+        pub keypoololdest: u64,
+        pub keypoolsize: u32,
+        pub paytxfee: crate::ZecAmount,
         pub seedfp: String,
-        pub shielded_balance: rust_decimal::Decimal,
-        pub shielded_unconfirmed_balance: rust_decimal::Decimal,
-        pub txcount: rust_decimal::Decimal,
-        pub unconfirmed_balance: rust_decimal::Decimal,
-        pub unlocked_until: rust_decimal::Decimal,
-        pub walletversion: rust_decimal::Decimal,
+        pub shielded_balance: crate::ZecAmount,
+        pub shielded_unconfirmed_balance: crate::ZecAmount,
+        pub txcount: u64,
+        pub unconfirmed_balance: crate::ZecAmount,
+        pub unlocked_until: Option<u64>,
+        pub walletversion: u32,
     }
 }
 pub mod help {
@@ -860,7 +905,7 @@ pub mod keypoolrefill {
 pub mod listaccounts {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct ListaccountsResponse {
-        pub account: rust_decimal::Decimal,
+        pub account: crate::ZecAmount,
     }
 }
 pub mod listaddressgroupings {
@@ -873,8 +918,8 @@ pub mod listbanned {
 pub mod listlockunspent {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Listlockunspent {
-        pub txid: String,
-        pub vout: rust_decimal::Decimal,
+        pub txid: crate::TxId,
+        pub vout: u32,
     }
     pub type ListlockunspentResponse = Vec<Listlockunspent>;
 }
@@ -882,9 +927,11 @@ pub mod listreceivedbyaccount {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Listreceivedbyaccount {
         pub account: String,
-        pub amount: rust_decimal::Decimal,
-        pub amount_zat: rust_decimal::Decimal,
-        pub confirmations: rust_decimal::Decimal,
+        pub amount: crate::ZecAmount,
+        #[serde(rename = "amountZat")]
+        pub amount_zat: crate::Zatoshis,
+        pub confirmations: i64,
+        #[serde(rename = "involvesWatchonly")]
         pub involves_watchonly: bool,
     }
     pub type ListreceivedbyaccountResponse = Vec<Listreceivedbyaccount>;
@@ -893,10 +940,12 @@ pub mod listreceivedbyaddress {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Listreceivedbyaddress {
         pub account: String,
-        pub address: String,
-        pub amount: rust_decimal::Decimal,
-        pub amount_zat: rust_decimal::Decimal,
-        pub confirmations: rust_decimal::Decimal,
+        pub address: crate::Address,
+        pub amount: crate::ZecAmount,
+        #[serde(rename = "amountZat")]
+        pub amount_zat: crate::Zatoshis,
+        pub confirmations: i64,
+        #[serde(rename = "involvesWatchonly")]
         pub involves_watchonly: bool,
     }
     pub type ListreceivedbyaddressResponse = Vec<Listreceivedbyaddress>;
@@ -904,7 +953,7 @@ pub mod listreceivedbyaddress {
 pub mod listsinceblock {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct ListsinceblockResponse {
-        pub lastblock: String,
+        pub lastblock: crate::BlockHash,
         pub transactions: Vec<String>,
     }
 }
@@ -912,22 +961,23 @@ pub mod listtransactions {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Listtransactions {
         pub account: String,
-        pub address: String,
-        pub amount: rust_decimal::Decimal,
-        pub amount_zat: rust_decimal::Decimal,
-        pub blockhash: String,
-        pub blockindex: rust_decimal::Decimal,
+        pub address: crate::Address,
+        pub amount: crate::ZecAmount,
+        #[serde(rename = "amountZat")]
+        pub amount_zat: crate::Zatoshis,
+        pub blockhash: Option<crate::BlockHash>,
+        pub blockindex: u32,
         pub category: String,
         pub comment: String,
-        pub confirmations: rust_decimal::Decimal,
-        pub fee: rust_decimal::Decimal,
+        pub confirmations: i64,
+        pub fee: crate::ZecAmount,
         pub otheraccount: String,
-        pub size: rust_decimal::Decimal,
+        pub size: u64,
         pub status: String,
-        pub time: rust_decimal::Decimal,
-        pub timereceived: rust_decimal::Decimal,
-        pub txid: String,
-        pub vout: rust_decimal::Decimal,
+        pub time: u64,
+        pub timereceived: u64,
+        pub txid: crate::TxId,
+        pub vout: u32,
     }
     pub type ListtransactionsResponse = Vec<Listtransactions>;
 }
@@ -935,16 +985,19 @@ pub mod listunspent {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Listunspent {
         pub account: String,
-        pub address: String,
-        pub amount: rust_decimal::Decimal,
-        pub amount_zat: rust_decimal::Decimal,
-        pub confirmations: rust_decimal::Decimal,
+        pub address: crate::Address,
+        pub amount: crate::ZecAmount,
+        #[serde(rename = "amountZat")]
+        pub amount_zat: crate::Zatoshis,
+        pub confirmations: i64,
         pub generated: bool,
-        pub redeem_script: String,
-        pub script_pub_key: String,
+        #[serde(rename = "redeemScript")]
+        pub redeem_script: crate::HexBytes,
+        #[serde(rename = "scriptPubKey")]
+        pub script_pub_key: crate::HexBytes,
         pub spendable: bool,
-        pub txid: String,
-        pub vout: rust_decimal::Decimal,
+        pub txid: crate::TxId,
+        pub vout: u32,
     }
     pub type ListunspentResponse = Vec<Listunspent>;
 }
@@ -968,7 +1021,7 @@ pub mod sendmany {
     pub type SendmanyResponse = String;
 }
 pub mod sendrawtransaction {
-    pub type SendrawtransactionResponse = String;
+    pub type SendrawtransactionResponse = crate::TxId;
 }
 pub mod sendtoaddress {
     pub type SendtoaddressResponse = String;
@@ -999,16 +1052,17 @@ pub mod signrawtransaction {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Errors {
         pub error: String,
+        #[serde(rename = "scriptSig")]
         pub script_sig: String,
-        pub sequence: rust_decimal::Decimal,
-        pub txid: String,
-        pub vout: rust_decimal::Decimal,
+        pub sequence: u32,
+        pub txid: crate::TxId,
+        pub vout: u32,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct SignrawtransactionResponse {
         pub complete: bool,
         pub errors: Vec<Errors>,
-        pub hex: String,
+        pub hex: crate::HexBytes,
     }
 }
 pub mod stop {
@@ -1034,13 +1088,14 @@ pub mod validateaddress {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct ValidateaddressResponse {
         pub account: String,
-        pub address: String,
+        pub address: crate::Address,
         pub iscompressed: bool,
         pub ismine: bool,
         pub isscript: bool,
         pub isvalid: bool,
-        pub pubkey: String,
-        pub script_pub_key: String,
+        pub pubkey: crate::HexBytes,
+        #[serde(rename = "scriptPubKey")]
+        pub script_pub_key: crate::HexBytes,
     }
 }
 pub mod verifychain {
@@ -1062,29 +1117,29 @@ pub mod z_exportwallet {
     pub type ZExportwalletResponse = String;
 }
 pub mod z_getbalance {
-    pub type ZGetbalanceResponse = rust_decimal::Decimal;
+    pub type ZGetbalanceResponse = crate::ZecAmount;
 }
 pub mod z_getmigrationstatus {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct ZGetmigrationstatusResponse {
-        pub time_started: Option<rust_decimal::Decimal>,
-        pub destination_address: String,
+        pub time_started: Option<u64>,
+        pub destination_address: crate::Address,
         pub enabled: bool,
-        pub finalized_migrated_amount: rust_decimal::Decimal,
-        pub finalized_migration_transactions: rust_decimal::Decimal,
-        pub migration_txids: Vec<String>,
-        pub unfinalized_migrated_amount: rust_decimal::Decimal,
-        pub unmigrated_amount: rust_decimal::Decimal,
+        pub finalized_migrated_amount: crate::ZecAmount,
+        pub finalized_migration_transactions: u32,
+        pub migration_txids: Vec<crate::TxId>,
+        pub unfinalized_migrated_amount: crate::ZecAmount,
+        pub unmigrated_amount: crate::ZecAmount,
     }
 }
 pub mod z_getnewaddress {
-    pub type ZGetnewaddressResponse = String;
+    pub type ZGetnewaddressResponse = crate::Address;
 }
 pub mod z_getnotescount {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct ZGetnotescountResponse {
-        pub sapling: rust_decimal::Decimal,
-        pub sprout: rust_decimal::Decimal,
+        pub sapling: u32,
+        pub sprout: u32,
     }
 }
 pub mod z_getpaymentdisclosure {
@@ -1093,31 +1148,35 @@ pub mod z_getpaymentdisclosure {
 pub mod z_gettotalbalance {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct ZGettotalbalanceResponse {
-        pub private: rust_decimal::Decimal,
-        pub total: rust_decimal::Decimal,
-        pub transparent: rust_decimal::Decimal,
+        pub private: crate::ZecAmount,
+        pub total: crate::ZecAmount,
+        pub transparent: crate::ZecAmount,
     }
 }
 pub mod z_gettreestate {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Commitments {
-        pub final_root: String,
-        pub final_state: String,
+        #[serde(rename = "finalRoot")]
+        pub final_root: crate::MerkleRoot,
+        #[serde(rename = "finalState")]
+        pub final_state: Option<crate::HexBytes>,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Sapling {
         pub commitments: Commitments,
-        pub skip_hash: String,
+        #[serde(rename = "skipHash")]
+        pub skip_hash: Option<crate::BlockHash>,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Sprout {
         pub commitments: Commitments,
-        pub skip_hash: String,
+        #[serde(rename = "skipHash")]
+        pub skip_hash: Option<crate::BlockHash>,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct ZGettreestateResponse {
-        pub hash: String,
-        pub height: rust_decimal::Decimal,
+        pub hash: crate::BlockHash,
+        pub height: u32,
         pub sapling: Sapling,
         pub sprout: Sprout,
     }
@@ -1125,7 +1184,7 @@ pub mod z_gettreestate {
 pub mod z_importkey {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct ZImportkeyResponse {
-        pub address: String,
+        pub address: crate::Address,
         #[serde(rename = "type")]
         pub type_field: String,
     }
@@ -1133,7 +1192,7 @@ pub mod z_importkey {
 pub mod z_importviewingkey {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct ZImportviewingkeyResponse {
-        pub address: String,
+        pub address: crate::Address,
         #[serde(rename = "type")]
         pub type_field: String,
     }
@@ -1143,56 +1202,66 @@ pub mod z_importwallet {
     pub struct ZImportwalletResponse;
 }
 pub mod z_listaddresses {
-    pub type ZListaddressesResponse = Vec<String>;
+    pub type ZListaddressesResponse = Vec<crate::Address>;
 }
 pub mod z_listoperationids {
     pub type ZListoperationidsResponse = Vec<String>;
 }
 pub mod z_listreceivedbyaddress {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
-    pub struct ZListreceivedbyaddressResponse {
-        pub amount: rust_decimal::Decimal,
-        pub amount_zat: rust_decimal::Decimal,
-        pub blockheight: rust_decimal::Decimal,
-        pub blockindex: rust_decimal::Decimal,
-        pub blocktime: rust_decimal::Decimal,
+    pub struct ZListreceivedbyaddress {
+        pub amount: crate::ZecAmount,
+        #[serde(rename = "amountZat")]
+        pub amount_zat: crate::Zatoshis,
+        pub blockheight: u32,
+        pub blockindex: u32,
+        pub blocktime: Option<u64>,
         pub change: bool,
-        pub confirmations: rust_decimal::Decimal,
-        pub jsindex: rust_decimal::Decimal,
-        pub jsoutindex: rust_decimal::Decimal,
-        pub memo: String,
-        pub outindex: rust_decimal::Decimal,
-        pub txid: String,
+        pub confirmations: i64,
+        pub jsindex: Option<u32>,
+        pub jsoutindex: Option<u32>,
+        pub memo: crate::Memo,
+        pub outindex: Option<u32>,
+        pub txid: crate::TxId,
     }
+    pub type ZListreceivedbyaddressResponse = Vec<ZListreceivedbyaddress>;
 }
 pub mod z_listunspent {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct ZListunspent {
-        pub address: String,
-        pub amount: rust_decimal::Decimal,
+        pub address: crate::Address,
+        pub amount: crate::ZecAmount,
         pub change: bool,
-        pub confirmations: rust_decimal::Decimal,
-        pub jsindex: rust_decimal::Decimal,
-        pub jsoutindex: rust_decimal::Decimal,
-        pub memo: String,
-        pub outindex: rust_decimal::Decimal,
+        pub confirmations: i64,
+        pub jsindex: Option<u32>,
+        pub jsoutindex: Option<u32>,
+        pub memo: crate::Memo,
+        pub outindex: Option<u32>,
         pub spendable: bool,
-        pub txid: String,
+        pub txid: crate::TxId,
     }
     pub type ZListunspentResponse = Vec<ZListunspent>;
 }
 pub mod z_mergetoaddress {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct ZMergetoaddressResponse {
-        pub merging_notes: rust_decimal::Decimal,
-        pub merging_shielded_value: rust_decimal::Decimal,
-        pub merging_transparent_value: rust_decimal::Decimal,
-        pub merging_u_t_x_os: rust_decimal::Decimal,
+        #[serde(rename = "mergingNotes")]
+        pub merging_notes: u32,
+        #[serde(rename = "mergingShieldedValue")]
+        pub merging_shielded_value: crate::ZecAmount,
+        #[serde(rename = "mergingTransparentValue")]
+        pub merging_transparent_value: crate::ZecAmount,
+        #[serde(rename = "mergingUTXOs")]
+        pub merging_u_t_x_os: u32,
         pub opid: String,
-        pub remaining_notes: rust_decimal::Decimal,
-        pub remaining_shielded_value: rust_decimal::Decimal,
-        pub remaining_transparent_value: rust_decimal::Decimal,
-        pub remaining_u_t_x_os: rust_decimal::Decimal,
+        #[serde(rename = "remainingNotes")]
+        pub remaining_notes: u32,
+        #[serde(rename = "remainingShieldedValue")]
+        pub remaining_shielded_value: crate::ZecAmount,
+        #[serde(rename = "remainingTransparentValue")]
+        pub remaining_transparent_value: crate::ZecAmount,
+        #[serde(rename = "remainingUTXOs")]
+        pub remaining_u_t_x_os: u32,
     }
 }
 pub mod z_sendmany {
@@ -1206,16 +1275,20 @@ pub mod z_shieldcoinbase {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct ZShieldcoinbaseResponse {
         pub opid: String,
-        pub remaining_u_t_x_os: rust_decimal::Decimal,
-        pub remaining_value: rust_decimal::Decimal,
-        pub shielding_u_t_x_os: rust_decimal::Decimal,
-        pub shielding_value: rust_decimal::Decimal,
+        #[serde(rename = "remainingUTXOs")]
+        pub remaining_u_t_x_os: u32,
+        #[serde(rename = "remainingValue")]
+        pub remaining_value: crate::ZecAmount,
+        #[serde(rename = "shieldingUTXOs")]
+        pub shielding_u_t_x_os: u32,
+        #[serde(rename = "shieldingValue")]
+        pub shielding_value: crate::ZecAmount,
     }
 }
 pub mod z_validateaddress {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct ZValidateaddressResponse {
-        pub address: String,
+        pub address: crate::Address,
         pub diversifiedtransmissionkey: String,
         pub diversifier: String,
         pub ismine: bool,
@@ -1233,38 +1306,47 @@ pub mod z_validatepaymentdisclosure {
 pub mod z_viewtransaction {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Outputs {
-        pub address: String,
-        pub js: rust_decimal::Decimal,
-        pub js_output: rust_decimal::Decimal,
-        pub memo: String,
-        pub memo_str: String,
+        pub address: crate::Address,
+        pub js: Option<u32>,
+        #[serde(rename = "jsOutput")]
+        pub js_output: Option<u32>,
+        pub memo: crate::Memo,
+        #[serde(rename = "memoStr")]
+        pub memo_str: Option<String>,
         pub outgoing: bool,
-        pub output: rust_decimal::Decimal,
+        pub output: Option<u32>,
         #[serde(rename = "type")]
         pub type_field: String,
-        pub value: rust_decimal::Decimal,
-        pub value_zat: rust_decimal::Decimal,
+        pub value: crate::ZecAmount,
+        #[serde(rename = "valueZat")]
+        pub value_zat: crate::Zatoshis,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Spends {
-        pub address: String,
-        pub js: rust_decimal::Decimal,
-        pub js_output_prev: rust_decimal::Decimal,
-        pub js_prev: rust_decimal::Decimal,
-        pub js_spend: rust_decimal::Decimal,
-        pub output_prev: rust_decimal::Decimal,
-        pub spend: rust_decimal::Decimal,
-        pub txid_prev: String,
+        pub address: crate::Address,
+        pub js: Option<u32>,
+        #[serde(rename = "jsOutputPrev")]
+        pub js_output_prev: Option<u32>,
+        #[serde(rename = "jsPrev")]
+        pub js_prev: Option<u32>,
+        #[serde(rename = "jsSpend")]
+        pub js_spend: Option<u32>,
+        #[serde(rename = "outputPrev")]
+        pub output_prev: Option<u32>,
+        pub spend: Option<u32>,
+        #[serde(rename = "txidPrev")]
+        pub txid_prev: crate::TxId,
         #[serde(rename = "type")]
         pub type_field: String,
-        pub value: rust_decimal::Decimal,
-        pub value_zat: rust_decimal::Decimal,
+        pub value: crate::ZecAmount,
+        #[serde(rename = "valueZat")]
+        pub value_zat: crate::Zatoshis,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct ZViewtransactionResponse {
         pub outputs: Vec<Outputs>,
         pub spends: Vec<Spends>,
-        pub txid: String,
+        pub txid: crate::TxId,
     }
 }
 pub mod zcbenchmark {
@@ -1285,7 +1367,7 @@ pub mod zcrawjoinsplit {
 pub mod zcrawkeygen {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct ZcrawkeygenResponse {
-        pub zcaddress: String,
+        pub zcaddress: crate::Address,
         pub zcsecretkey: String,
         pub zcviewingkey: String,
     }
@@ -1293,7 +1375,7 @@ pub mod zcrawkeygen {
 pub mod zcrawreceive {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct ZcrawreceiveResponse {
-        pub amount: rust_decimal::Decimal,
+        pub amount: crate::ZecAmount,
         pub exists: bool,
         pub note: String,
     }
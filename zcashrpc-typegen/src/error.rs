@@ -9,6 +9,26 @@ pub enum TypegenError {
     Annotation(QuizfaceAnnotationError),
 }
 
+impl std::fmt::Display for TypegenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TypegenError::Filesystem(e) => write!(f, "{}", e),
+            TypegenError::Json(e) => write!(f, "{}", e),
+            TypegenError::Annotation(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for TypegenError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TypegenError::Filesystem(e) => Some(e),
+            TypegenError::Json(e) => Some(e),
+            TypegenError::Annotation(e) => Some(e),
+        }
+    }
+}
+
 #[derive(Debug)]
 #[cfg_attr(test, derive(PartialEq))]
 pub struct FSError {
@@ -27,6 +47,14 @@ impl FSError {
     }
 }
 
+impl std::fmt::Display for FSError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.location.display(), self.message)
+    }
+}
+
+impl std::error::Error for FSError {}
+
 #[derive(Debug)]
 pub struct JsonError {
     err: serde_json::Error,
@@ -42,6 +70,18 @@ impl JsonError {
     }
 }
 
+impl std::fmt::Display for JsonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid JSON: {}", self.err)
+    }
+}
+
+impl std::error::Error for JsonError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.err)
+    }
+}
+
 #[derive(Debug, FromWrapped)]
 #[cfg_attr(test, derive(PartialEq))]
 pub struct QuizfaceAnnotationError {
@@ -49,6 +89,26 @@ pub struct QuizfaceAnnotationError {
     pub location: String,
 }
 
+impl std::fmt::Display for QuizfaceAnnotationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid annotation at {}: ", self.location)?;
+        match &self.kind {
+            InvalidAnnotationKind::Null => f.write_str("null"),
+            InvalidAnnotationKind::Bool(b) => write!(f, "boolean {}", b),
+            InvalidAnnotationKind::Number(n) => write!(f, "number {}", n),
+            InvalidAnnotationKind::InvalidString(label) => {
+                write!(f, "unknown label {:?}", label)
+            }
+            InvalidAnnotationKind::EmptyArray => f.write_str("empty array"),
+            InvalidAnnotationKind::Insufficient => {
+                f.write_str("marked INSUFFICIENT")
+            }
+        }
+    }
+}
+
+impl std::error::Error for QuizfaceAnnotationError {}
+
 #[derive(Debug, PartialEq)]
 pub enum InvalidAnnotationKind {
     Null,
@@ -97,6 +157,7 @@ mod unit {
         let err =
             crate::tokenize::value("foo", serde_json::Value::Null, Vec::new())
                 .unwrap_err();
+        assert_eq!(err.to_string(), "invalid annotation at foo: null");
         assert_eq!(TypegenError::Annotation(expected_err), err);
     }
     #[test]
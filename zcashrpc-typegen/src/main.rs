@@ -77,6 +77,7 @@ fn camel_to_under(name: &str) -> String {
 fn process_response(file: &std::path::Path) -> TypegenResult<TokenStream> {
     let acc = Vec::new();
     let (file_name, file_body) = get_data(file);
+    special_cases::enter_module(&file_name);
     let mod_name = callsite_ident(&if special_cases::RESERVED_KEYWORDS
         .contains(&file_name.as_ref())
     {
@@ -85,7 +86,8 @@ fn process_response(file: &std::path::Path) -> TypegenResult<TokenStream> {
         file_name.clone()
     });
     let name = [under_to_camel(&file_name), "Response".to_string()].concat();
-    let mut output = match file_body {
+    let mut output = match special_cases::array_response(&file_name, file_body)
+    {
         serde_json::Value::Array(mut vec) => match vec.len() {
             0 => emptygen(&name, acc),
             1 => match vec.pop().unwrap() {
@@ -174,20 +176,12 @@ fn callsite_ident(name: &str) -> proc_macro2::Ident {
 }
 
 fn handle_options_standalones_and_keywords(
+    struct_name: &str,
     serde_rename: &mut Option<TokenStream>,
     field_name: &mut String,
     atomic_response: &mut bool,
     option: &mut bool,
 ) -> () {
-    if special_cases::RESERVED_KEYWORDS.contains(&field_name.as_str()) {
-        *serde_rename = Some(
-            format!("#[serde(rename = \"{}\")]", &field_name)
-                .parse()
-                .unwrap(),
-        );
-        field_name.push_str("_field");
-    }
-
     if field_name.starts_with("alsoStandalone<") {
         *field_name = field_name
             .trim_end_matches(">")
@@ -201,6 +195,19 @@ fn handle_options_standalones_and_keywords(
             .to_string();
         *option = true;
     }
+    if special_cases::is_optional(struct_name, field_name) {
+        *option = true;
+    }
+
+    // From here on field_name is the Rust name, so anything that no longer
+    // matches the name zcashd sends needs a rename
+    let wire_name = std::mem::replace(field_name, camel_to_under(field_name));
+    if special_cases::RESERVED_KEYWORDS.contains(&field_name.as_str()) {
+        field_name.push_str("_field");
+    }
+    if *field_name != wire_name {
+        *serde_rename = Some(quote!(#[serde(rename = #wire_name)]));
+    }
 }
 
 fn enumgen(
@@ -212,46 +219,24 @@ fn enumgen(
 ) -> TypegenResult<Vec<TokenStream>> {
     assert!(inner_nodes.len() <= VARIANT_NAMES.len());
     let ident = callsite_ident(enum_name);
+    // Every variant wraps a single type, so that callers who know which
+    // variant to expect (e.g. from the requested verbosity) can name it.
     let enum_code: Vec<TokenStream> = inner_nodes
         .into_iter()
         .zip(VARIANT_NAMES.iter())
         .map(|(value, variant_name)| {
-            let variant_name = capitalize_first_char(&variant_name);
-            let variant_name_tokens = callsite_ident(&variant_name);
-            match value {
-                serde_json::Value::Object(obj) => {
-                    let field_data = handle_fields(enum_name, obj)?;
-                    acc.extend(field_data.new_code);
-                    match field_data.case {
-                        special_cases::Case::Regular => {
-                            let variant_body_tokens =
-                                field_data.ident_val_tokens;
-                            Ok(quote!(
-                            #variant_name_tokens {
-                                #(#variant_body_tokens)*
-                            },))
-                        }
-                        other_case => unimplemented!(
-                            "Hit special case {:?} in enumgen",
-                            other_case
-                        ),
-                    }
-                }
-                non_object => {
-                    let (variant_body_tokens, new_acc, _terminal_enum) =
-                        tokenize::value(
-                            &variant_name,
-                            non_object,
-                            acc.clone(),
-                        )?;
-                    acc = new_acc;
-                    Ok(quote!(#variant_name_tokens(#variant_body_tokens),))
-                }
-            }
+            let variant_name_tokens = callsite_ident(variant_name);
+            let (variant_body_tokens, new_acc, _terminal_enum) =
+                tokenize::value(variant_name, value, acc.clone())?;
+            acc = new_acc;
+            Ok(quote!(#variant_name_tokens(#variant_body_tokens),))
         })
         .collect::<TypegenResult<Vec<TokenStream>>>()?;
+    // zcashd doesn't tag its responses with the variant, so the variant is
+    // picked by which one the response's shape deserializes into, in order.
     acc.push(quote!(
             #[derive(Debug, serde::Deserialize, serde::Serialize)]
+            #[serde(untagged)]
             pub enum #ident {
                 #(#enum_code)*
             }
@@ -332,9 +317,10 @@ fn handle_fields(
     let mut new_code = Vec::new();
     let mut atomic_response = true;
     let mut case = special_cases::Case::Regular;
+    let inner_nodes = special_cases::add_extra_fields(struct_name, inner_nodes);
     for (mut field_name, val) in inner_nodes {
         //special case handling
-        if &field_name == "xxxx" {
+        if special_cases::MAP_KEY_PLACEHOLDERS.contains(&field_name.as_str()) {
             new_code = tokenize::value(struct_name, val, Vec::new())?.1; // .0 unused
             case = special_cases::Case::FourXs;
             break;
@@ -343,12 +329,14 @@ fn handle_fields(
         let mut serde_rename = None;
         let mut option = false;
         handle_options_standalones_and_keywords(
+            struct_name,
             &mut serde_rename,
             &mut field_name,
             &mut atomic_response,
             &mut option,
         );
-        field_name = camel_to_under(&field_name);
+        let val =
+            special_cases::override_terminal(struct_name, &field_name, val);
 
         //temp_acc needed because destructuring assignments are unstable
         //see https://github.com/rust-lang/rust/issues/71126 for more info
@@ -389,6 +377,7 @@ fn alias(
     acc: Vec<TokenStream>,
 ) -> TypegenResult<Vec<TokenStream>> {
     let ident = callsite_ident(&name);
+    let data = special_cases::override_response(name, data);
     let (type_body, mut acc, terminal_enum) = tokenize::value(
         &capitalize_first_char(name.trim_end_matches("Response")),
         data,
@@ -494,16 +483,13 @@ mod unit {
 mod test_consts {
     pub(super) const GETINFO_RESPONSE: &str = "pub mod getinfo { # [derive \
     (Debug , serde :: Deserialize , serde :: Serialize)] pub struct \
-    GetinfoResponse { pub proxy : Option < String > , pub balance : \
-    rust_decimal :: Decimal , pub blocks : rust_decimal :: Decimal , pub \
-    connections : rust_decimal :: Decimal , pub difficulty : rust_decimal :: \
-    Decimal , pub errors : String , pub keypoololdest : rust_decimal :: \
-    Decimal , pub keypoolsize : rust_decimal :: Decimal , pub paytxfee : \
-    rust_decimal :: Decimal , pub protocolversion : rust_decimal :: Decimal , \
-    pub relayfee : rust_decimal :: Decimal , pub testnet : bool , pub \
-    timeoffset : rust_decimal :: Decimal , pub unlocked_until : rust_decimal \
-    :: Decimal , pub version : rust_decimal :: Decimal , pub walletversion : \
-    rust_decimal :: Decimal , } }";
+    GetinfoResponse { pub proxy : Option < String > , pub balance : crate :: \
+    ZecAmount , pub blocks : u32 , pub connections : u32 , pub difficulty : \
+    rust_decimal :: Decimal , pub errors : String , pub keypoololdest : u64 , \
+    pub keypoolsize : u32 , pub paytxfee : crate :: ZecAmount , pub \
+    protocolversion : u32 , pub relayfee : crate :: ZecAmount , pub testnet : \
+    bool , pub timeoffset : i64 , pub unlocked_until : Option < u64 > , pub \
+    version : u32 , pub walletversion : u32 , } }";
     pub(super) const SIMPLE_UNNESTED_RESPONSE: &str = "# [derive (Debug , \
     serde :: Deserialize , serde :: Serialize)] pub struct somefield { pub \
     inner_a : String , pub inner_b : bool , pub inner_c : rust_decimal :: \
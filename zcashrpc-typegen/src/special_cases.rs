@@ -15,3 +15,373 @@ pub(crate) const RESERVED_KEYWORDS: &[&str] = &[
     "final", "macro", "offsetof", "override", "priv", "proc", "pure", "sizeof",
     "typeof", "unsized", "virtual", "yield",
 ];
+
+// zcashd omits these fields in some responses, although the help text
+// describes them unconditionally: e.g. the chain tip has no `nextblockhash`,
+// a mempool transaction has no `blockhash`, and `z_gettreestate` reports a
+// `skipHash` instead of the `finalState` of a tree the block didn't change.
+// The node which recorded `GetBlockChainInfoResponse.json` doesn't report
+// `initial_block_download_complete`.
+// `getinfo` and `getwalletinfo` only report `unlocked_until` for an
+// encrypted wallet.
+// The shielded wallet locates Sprout notes by `js*` fields and Sapling notes
+// by `outindex`, `output` or `spend`, and only sends `memoStr` for memos
+// which are text. A "Struct::field" key only applies to that struct: a
+// coinbase input has no `scriptSig`, `txid` or `vout`, the transactions of a
+// verbosity 2 `getblock` leave out what the block already says, and the
+// nulldata (OP_RETURN) and nonstandard outputs have no `addresses` or
+// `reqSigs`. A "module::Struct::field" key only applies to that struct of
+// one response: a mempool transaction has no `confirmations` or `time`.
+const OPTIONAL_FIELDS: &[&str] = &[
+    "ScriptPubKey::addresses",
+    "ScriptPubKey::reqSigs",
+    "Tx::confirmations",
+    "Tx::hex",
+    "Tx::time",
+    "Vin::coinbase",
+    "Vin::scriptSig",
+    "Vin::txid",
+    "Vin::vout",
+    "blockhash",
+    "blocktime",
+    "finalState",
+    "getrawtransaction::Verbose::confirmations",
+    "getrawtransaction::Verbose::time",
+    "in_active_chain",
+    "initial_block_download_complete",
+    "js",
+    "jsOutput",
+    "jsOutputPrev",
+    "jsPrev",
+    "jsSpend",
+    "jsindex",
+    "jsoutindex",
+    "memoStr",
+    "nextblockhash",
+    "outindex",
+    "output",
+    "outputPrev",
+    "previousblockhash",
+    "skipHash",
+    "spend",
+    "unlocked_until",
+];
+
+// Fields zcashd sends which its help text doesn't describe, as
+// (struct, field, annotation): the input of a coinbase transaction has the
+// coinbase script instead of a previous output.
+const EXTRA_FIELDS: &[(&str, &str, &str)] = &[("Vin", "coinbase", "String")];
+
+// zcashd sends these responses as an array of the object their help text
+// describes, e.g. one entry per note `z_listreceivedbyaddress` finds.
+const ARRAY_RESPONSES: &[&str] = &["z_listreceivedbyaddress"];
+
+// Keys which stand in for arbitrary map keys, e.g. the txids keying the
+// verbose `getrawmempool` response, rather than naming a field.
+pub(crate) const MAP_KEY_PLACEHOLDERS: &[&str] = &["xxxx", "transactionid"];
+
+// Quizface annotates every number as "Decimal", whatever zcashd actually
+// sends. These replace the "Decimal" annotation of a field, keyed by the
+// generated (snake_case) field name, so counts, heights and timestamps get
+// integer types and amounts get their unit.
+const DECIMAL_OVERRIDES: &[(&str, &str)] = &[
+    ("nonce", "hexadecimal"),
+    // ZEC-denominated amounts
+    ("ZGettotalbalanceResponse::private", "ZecAmount"),
+    ("ZGettotalbalanceResponse::total", "ZecAmount"),
+    ("ZGettotalbalanceResponse::transparent", "ZecAmount"),
+    ("account", "ZecAmount"),
+    ("amount", "ZecAmount"),
+    ("balance", "ZecAmount"),
+    ("fee", "ZecAmount"),
+    ("finalized_migrated_amount", "ZecAmount"),
+    ("founders", "ZecAmount"),
+    ("immature_balance", "ZecAmount"),
+    ("merging_shielded_value", "ZecAmount"),
+    ("merging_transparent_value", "ZecAmount"),
+    ("miner", "ZecAmount"),
+    ("paytxfee", "ZecAmount"),
+    ("relayfee", "ZecAmount"),
+    ("remaining_shielded_value", "ZecAmount"),
+    ("remaining_transparent_value", "ZecAmount"),
+    ("remaining_value", "ZecAmount"),
+    ("shielded_balance", "ZecAmount"),
+    ("shielded_unconfirmed_balance", "ZecAmount"),
+    ("shielding_value", "ZecAmount"),
+    ("total_amount", "ZecAmount"),
+    ("unconfirmed_balance", "ZecAmount"),
+    ("unfinalized_migrated_amount", "ZecAmount"),
+    ("unmigrated_amount", "ZecAmount"),
+    ("value", "ZecAmount"),
+    ("vpub_new", "ZecAmount"),
+    ("vpub_old", "ZecAmount"),
+    // zatoshi-denominated amounts
+    ("amount_zat", "Zatoshis"),
+    ("satoshis", "Zatoshis"),
+    ("value_zat", "Zatoshis"),
+    // heights, indices and counts
+    ("activationheight", "u32"),
+    ("blockheight", "u32"),
+    ("blockindex", "u32"),
+    ("blocks", "u32"),
+    ("branchlen", "u32"),
+    ("changepos", "u32"),
+    ("connections", "u32"),
+    ("currentblocktx", "u32"),
+    ("deprecationheight", "u32"),
+    ("estimatedheight", "u32"),
+    ("expiryheight", "u32"),
+    ("finalized_migration_transactions", "u32"),
+    ("found", "u32"),
+    ("headers", "u32"),
+    ("height", "u32"),
+    ("index", "u32"),
+    ("js", "u32"),
+    ("js_output", "u32"),
+    ("js_output_prev", "u32"),
+    ("js_prev", "u32"),
+    ("js_spend", "u32"),
+    ("jsindex", "u32"),
+    ("jsoutindex", "u32"),
+    ("keypoolsize", "u32"),
+    ("locktime", "u32"),
+    ("merging_notes", "u32"),
+    ("merging_u_t_x_os", "u32"),
+    ("n", "u32"),
+    ("outindex", "u32"),
+    ("output", "u32"),
+    ("output_index", "u32"),
+    ("output_prev", "u32"),
+    ("pooledtx", "u32"),
+    ("prevout", "u32"),
+    ("port", "u32"),
+    ("protocolversion", "u32"),
+    ("remaining_notes", "u32"),
+    ("remaining_u_t_x_os", "u32"),
+    ("req_sigs", "u32"),
+    ("required", "u32"),
+    ("sapling", "u32"),
+    ("score", "u32"),
+    ("sequence", "u32"),
+    ("shielding_u_t_x_os", "u32"),
+    ("spend", "u32"),
+    ("sprout", "u32"),
+    ("startingheight", "u32"),
+    ("version", "u32"),
+    ("vout", "u32"),
+    ("walletversion", "u32"),
+    ("window", "u32"),
+    // sizes, byte counts, big counts and timestamps
+    ("blocktime", "u64"),
+    ("bytes", "u64"),
+    ("bytes_left_in_cycle", "u64"),
+    ("bytes_serialized", "u64"),
+    ("bytesrecv", "u64"),
+    ("bytessent", "u64"),
+    ("chunks_free", "u64"),
+    ("chunks_used", "u64"),
+    ("commitments", "u64"),
+    ("conntime", "u64"),
+    ("currentblocksize", "u64"),
+    ("free", "u64"),
+    ("id", "u64"),
+    ("keypoololdest", "u64"),
+    ("lastrecv", "u64"),
+    ("lastsend", "u64"),
+    ("locked", "u64"),
+    ("mediantime", "u64"),
+    ("size", "u64"),
+    ("size_on_disk", "u64"),
+    ("target", "u64"),
+    ("time", "u64"),
+    ("time_left_in_cycle", "u64"),
+    ("time_started", "u64"),
+    ("timeframe", "u64"),
+    ("timemillis", "u64"),
+    ("timereceived", "u64"),
+    ("timestamp", "u64"),
+    ("totalbytesrecv", "u64"),
+    ("totalbytessent", "u64"),
+    ("transactions", "u64"),
+    ("txcount", "u64"),
+    ("txouts", "u64"),
+    ("unlocked_until", "u64"),
+    ("usage", "u64"),
+    ("used", "u64"),
+    // integers zcashd reports as -1 when unknown or not applicable
+    ("banscore", "i64"),
+    ("confirmations", "i64"),
+    ("genproclimit", "i64"),
+    ("synced_blocks", "i64"),
+    ("synced_headers", "i64"),
+    ("timeoffset", "i64"),
+];
+
+// Likewise for "String" annotations of hashes, ids, hex-encoded bytes and
+// addresses. A "Struct::field" key takes precedence over a plain field key,
+// which keeps the ip:port `address` fields of peers as strings.
+const STRING_OVERRIDES: &[(&str, &str)] = &[
+    ("Addresses::address", "String"),
+    ("Localaddresses::address", "String"),
+    ("address", "Address"),
+    ("addresses", "Address"),
+    ("anchor", "MerkleRoot"),
+    ("bestblock", "BlockHash"),
+    ("bestblockhash", "BlockHash"),
+    ("blockhash", "BlockHash"),
+    ("chain", "Network"),
+    ("depends", "TxId"),
+    ("destination_address", "Address"),
+    ("final_root", "MerkleRoot"),
+    ("final_state", "hexadecimal"),
+    ("finalsaplingroot", "MerkleRoot"),
+    ("hash", "BlockHash"),
+    ("hex", "hexadecimal"),
+    ("lastblock", "BlockHash"),
+    ("memo", "Memo"),
+    ("merkleroot", "MerkleRoot"),
+    ("migration_txids", "TxId"),
+    ("nextblockhash", "BlockHash"),
+    ("p2sh", "Address"),
+    ("previousblockhash", "BlockHash"),
+    ("prevtxid", "TxId"),
+    ("pubkey", "hexadecimal"),
+    ("redeem_script", "hexadecimal"),
+    ("script", "hexadecimal"),
+    ("script_pub_key", "hexadecimal"),
+    ("Vin::coinbase", "hexadecimal"),
+    ("skip_hash", "BlockHash"),
+    ("tx", "TxId"),
+    ("txid", "TxId"),
+    ("txid_prev", "TxId"),
+    ("zcaddress", "Address"),
+];
+
+// The overrides for a field, keyed by the annotation they replace.
+const TERMINAL_OVERRIDES: &[(&str, &[(&str, &str)])] =
+    &[("Decimal", DECIMAL_OVERRIDES), ("String", STRING_OVERRIDES)];
+
+// Like TERMINAL_OVERRIDES, for responses which are a bare terminal, keyed by
+// the name of the generated type alias.
+const RESPONSE_OVERRIDES: &[(&str, &str)] = &[
+    ("EstimatefeeResponse", "ZecAmount"),
+    ("GetaccountaddressResponse", "Address"),
+    ("GetaddressesbyaccountResponse", "Address"),
+    ("GetbalanceResponse", "ZecAmount"),
+    ("GetbestblockhashResponse", "BlockHash"),
+    ("GetblockcountResponse", "u32"),
+    ("GetblockhashResponse", "BlockHash"),
+    ("GetconnectioncountResponse", "u32"),
+    ("GetnewaddressResponse", "Address"),
+    ("GetrawchangeaddressResponse", "Address"),
+    ("GetreceivedbyaccountResponse", "ZecAmount"),
+    ("GetreceivedbyaddressResponse", "ZecAmount"),
+    ("GetunconfirmedbalanceResponse", "ZecAmount"),
+    ("SendrawtransactionResponse", "TxId"),
+    ("ZGetbalanceResponse", "ZecAmount"),
+    ("ZGetnewaddressResponse", "Address"),
+    ("ZListaddressesResponse", "Address"),
+];
+
+thread_local! {
+    // The module of the response being generated, which a
+    // "module::Struct::field" key of OPTIONAL_FIELDS is scoped to, since
+    // e.g. several modules have a `Verbose` struct.
+    static MODULE: std::cell::RefCell<String> = Default::default();
+}
+
+// Scopes the OPTIONAL_FIELDS keys to the module `name`, until the next call.
+pub(crate) fn enter_module(name: &str) {
+    MODULE.with(|module| *module.borrow_mut() = name.to_string());
+}
+
+// Whether `struct_name`'s field `field_name`, as zcashd names it, is one of
+// the OPTIONAL_FIELDS.
+pub(crate) fn is_optional(struct_name: &str, field_name: &str) -> bool {
+    let scoped = format!("{}::{}", struct_name, field_name);
+    let in_module =
+        MODULE.with(|module| format!("{}::{}", module.borrow(), scoped));
+    OPTIONAL_FIELDS.contains(&field_name)
+        || OPTIONAL_FIELDS.contains(&scoped.as_str())
+        || OPTIONAL_FIELDS.contains(&in_module.as_str())
+}
+
+// Adds the EXTRA_FIELDS of `struct_name` to the fields annotating it.
+pub(crate) fn add_extra_fields(
+    struct_name: &str,
+    mut fields: serde_json::Map<String, serde_json::Value>,
+) -> serde_json::Map<String, serde_json::Value> {
+    for (name, field, annotation) in EXTRA_FIELDS {
+        if *name == struct_name {
+            fields.insert(field.to_string(), serde_json::json!(annotation));
+        }
+    }
+    fields
+}
+
+// Wraps the object annotating one of the ARRAY_RESPONSES in an array.
+pub(crate) fn array_response(
+    file_name: &str,
+    data: serde_json::Value,
+) -> serde_json::Value {
+    match data {
+        serde_json::Value::Array(mut vec)
+            if ARRAY_RESPONSES.contains(&file_name)
+                && vec.len() == 1
+                && vec[0].is_object() =>
+        {
+            serde_json::Value::Array(vec![serde_json::Value::Array(vec![vec
+                .pop()
+                .unwrap()])])
+        }
+        otherwise => otherwise,
+    }
+}
+
+fn lookup(overrides: &[(&str, &str)], name: &str) -> Option<String> {
+    overrides
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, label)| label.to_string())
+}
+
+// Applies TERMINAL_OVERRIDES to the annotation of `struct_name`'s field, or
+// to the elements of an array of terminals.
+pub(crate) fn override_terminal(
+    struct_name: &str,
+    field_name: &str,
+    val: serde_json::Value,
+) -> serde_json::Value {
+    override_label(val, &|label| {
+        let (_, overrides) = TERMINAL_OVERRIDES
+            .iter()
+            .find(|(annotation, _)| *annotation == label)?;
+        lookup(overrides, &format!("{}::{}", struct_name, field_name))
+            .or_else(|| lookup(overrides, field_name))
+    })
+}
+
+// Applies RESPONSE_OVERRIDES to a terminal response, or to the elements of
+// an array of terminals.
+pub(crate) fn override_response(
+    response_name: &str,
+    val: serde_json::Value,
+) -> serde_json::Value {
+    override_label(val, &|_| lookup(RESPONSE_OVERRIDES, response_name))
+}
+
+fn override_label(
+    val: serde_json::Value,
+    replacement: &dyn Fn(&str) -> Option<String>,
+) -> serde_json::Value {
+    match val {
+        serde_json::Value::String(label) => {
+            serde_json::Value::String(replacement(&label).unwrap_or(label))
+        }
+        serde_json::Value::Array(mut elements) if elements.len() == 1 => {
+            let element = elements.pop().unwrap();
+            serde_json::Value::Array(vec![override_label(element, replacement)])
+        }
+        otherwise => otherwise,
+    }
+}
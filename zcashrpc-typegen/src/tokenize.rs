@@ -61,9 +61,20 @@ fn terminal(
     Ok((
         match label {
             "Decimal" => quote!(rust_decimal::Decimal),
+            "u32" => quote!(u32),
+            "u64" => quote!(u64),
+            "i64" => quote!(i64),
+            "ZecAmount" => quote!(crate::ZecAmount),
+            "Zatoshis" => quote!(crate::Zatoshis),
             "bool" => quote!(bool),
             "String" => quote!(String),
-            "hexadecimal" => quote!(String),
+            "hexadecimal" => quote!(crate::HexBytes),
+            "BlockHash" => quote!(crate::BlockHash),
+            "TxId" => quote!(crate::TxId),
+            "MerkleRoot" => quote!(crate::MerkleRoot),
+            "Address" => quote!(crate::Address),
+            "Memo" => quote!(crate::Memo),
+            "Network" => quote!(crate::Network),
             "INSUFFICIENT" => {
                 return Err(error::TypegenError::from(
                     error::QuizfaceAnnotationError {
@@ -3,23 +3,27 @@
 pub mod z_gettreestate {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Commitments {
-        pub final_root: String,
-        pub final_state: String,
+        #[serde(rename = "finalRoot")]
+        pub final_root: crate::MerkleRoot,
+        #[serde(rename = "finalState")]
+        pub final_state: Option<crate::HexBytes>,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Sapling {
         pub commitments: Commitments,
-        pub skip_hash: String,
+        #[serde(rename = "skipHash")]
+        pub skip_hash: Option<crate::BlockHash>,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Sprout {
         pub commitments: Commitments,
-        pub skip_hash: String,
+        #[serde(rename = "skipHash")]
+        pub skip_hash: Option<crate::BlockHash>,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct ZGettreestateResponse {
-        pub hash: String,
-        pub height: rust_decimal::Decimal,
+        pub hash: crate::BlockHash,
+        pub height: u32,
         pub sapling: Sapling,
         pub sprout: Sprout,
     }
@@ -2,39 +2,42 @@
 //is in early alpha, and output is subject to change at any time.
 pub mod getaddressdeltas {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    #[serde(untagged)]
     pub enum GetaddressdeltasResponse {
         Regular(Vec<Regular>),
-        Verbose {
-            deltas: Vec<Deltas>,
-            end: End,
-            start: Start,
-        },
+        Verbose(Verbose),
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Deltas {
-        pub address: String,
-        pub height: rust_decimal::Decimal,
-        pub index: rust_decimal::Decimal,
-        pub satoshis: rust_decimal::Decimal,
-        pub txid: String,
+        pub address: crate::Address,
+        pub height: u32,
+        pub index: u32,
+        pub satoshis: crate::Zatoshis,
+        pub txid: crate::TxId,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct End {
-        pub hash: String,
-        pub height: rust_decimal::Decimal,
+        pub hash: crate::BlockHash,
+        pub height: u32,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Regular {
-        pub address: String,
-        pub height: rust_decimal::Decimal,
-        pub index: rust_decimal::Decimal,
-        pub satoshis: rust_decimal::Decimal,
-        pub txid: String,
+        pub address: crate::Address,
+        pub height: u32,
+        pub index: u32,
+        pub satoshis: crate::Zatoshis,
+        pub txid: crate::TxId,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Start {
-        pub hash: String,
-        pub height: rust_decimal::Decimal,
+        pub hash: crate::BlockHash,
+        pub height: u32,
+    }
+    #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
+    pub struct Verbose {
+        pub deltas: Vec<Deltas>,
+        pub end: End,
+        pub start: Start,
     }
 }
 pub mod getblockchaininfo {
@@ -45,45 +48,45 @@ pub mod getblockchaininfo {
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Enforce {
-        pub found: rust_decimal::Decimal,
-        pub required: rust_decimal::Decimal,
+        pub found: u32,
+        pub required: u32,
         pub status: bool,
-        pub window: rust_decimal::Decimal,
+        pub window: u32,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct GetblockchaininfoResponse {
-        pub bestblockhash: String,
-        pub blocks: rust_decimal::Decimal,
-        pub chain: String,
+        pub bestblockhash: crate::BlockHash,
+        pub blocks: u32,
+        pub chain: crate::Network,
         pub chainwork: String,
-        pub commitments: rust_decimal::Decimal,
+        pub commitments: u64,
         pub consensus: Consensus,
         pub difficulty: rust_decimal::Decimal,
-        pub estimatedheight: rust_decimal::Decimal,
-        pub headers: rust_decimal::Decimal,
-        pub initial_block_download_complete: bool,
-        pub size_on_disk: rust_decimal::Decimal,
+        pub estimatedheight: u32,
+        pub headers: u32,
+        pub initial_block_download_complete: Option<bool>,
+        pub size_on_disk: u64,
         pub softforks: Vec<Softforks>,
         pub upgrades: std::collections::HashMap<String, Upgrades>,
         pub verificationprogress: rust_decimal::Decimal,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Reject {
-        pub found: rust_decimal::Decimal,
-        pub required: rust_decimal::Decimal,
+        pub found: u32,
+        pub required: u32,
         pub status: bool,
-        pub window: rust_decimal::Decimal,
+        pub window: u32,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Softforks {
         pub enforce: Enforce,
         pub id: String,
         pub reject: Reject,
-        pub version: rust_decimal::Decimal,
+        pub version: u32,
     }
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct Upgrades {
-        pub activationheight: rust_decimal::Decimal,
+        pub activationheight: u32,
         pub info: String,
         pub name: String,
         pub status: String,
@@ -93,20 +96,20 @@ pub mod getinfo {
     #[derive(Debug, serde :: Deserialize, serde :: Serialize)]
     pub struct GetinfoResponse {
         pub proxy: Option<String>,
-        pub balance: rust_decimal::Decimal,
-        pub blocks: rust_decimal::Decimal,
-        pub connections: rust_decimal::Decimal,
+        pub balance: crate::ZecAmount,
+        pub blocks: u32,
+        pub connections: u32,
         pub difficulty: rust_decimal::Decimal,
         pub errors: String,
-        pub keypoololdest: rust_decimal::Decimal,
-        pub keypoolsize: rust_decimal::Decimal,
-        pub paytxfee: rust_decimal::Decimal,
-        pub protocolversion: rust_decimal::Decimal,
-        pub relayfee: rust_decimal::Decimal,
+        pub keypoololdest: u64,
+        pub keypoolsize: u32,
+        pub paytxfee: crate::ZecAmount,
+        pub protocolversion: u32,
+        pub relayfee: crate::ZecAmount,
         pub testnet: bool,
-        pub timeoffset: rust_decimal::Decimal,
-        pub unlocked_until: rust_decimal::Decimal,
-        pub version: rust_decimal::Decimal,
-        pub walletversion: rust_decimal::Decimal,
+        pub timeoffset: i64,
+        pub unlocked_until: Option<u64>,
+        pub version: u32,
+        pub walletversion: u32,
     }
 }